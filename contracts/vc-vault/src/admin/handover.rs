@@ -0,0 +1,35 @@
+//! Two-step contract admin handover: the admin proposes, the new admin accepts.
+
+use crate::admin;
+use crate::error::ContractError;
+use crate::model::AdminHandover;
+use crate::storage;
+use soroban_sdk::{panic_with_error, Address, Env};
+
+/// Seconds a proposed handover stays acceptable (7 days).
+pub const HANDOVER_LIFETIME: u64 = 7 * 24 * 60 * 60;
+
+/// Record `new_admin` as the proposed admin, replacing any earlier proposal.
+pub fn propose_handover(e: &Env, new_admin: &Address) -> AdminHandover {
+    let handover = AdminHandover {
+        new_admin: new_admin.clone(),
+        expires_at: e.ledger().timestamp().saturating_add(HANDOVER_LIFETIME),
+    };
+    storage::write_pending_contract_admin(e, Some(&handover));
+    handover
+}
+
+/// Install the proposed admin as the sole signer. The new admin must sign.
+pub fn accept_handover(e: &Env) -> Address {
+    let handover = match storage::read_pending_contract_admin(e) {
+        Some(handover) => handover,
+        None => panic_with_error!(e, ContractError::NoHandoverPending),
+    };
+    if e.ledger().timestamp() > handover.expires_at {
+        panic_with_error!(e, ContractError::HandoverExpired)
+    }
+    handover.new_admin.require_auth();
+    storage::write_pending_contract_admin(e, None);
+    admin::reset_signers(e, &handover.new_admin);
+    handover.new_admin
+}
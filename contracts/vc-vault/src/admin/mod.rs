@@ -0,0 +1,161 @@
+//! Contract admin signer set: M-of-N approval of privileged operations.
+//!
+//! A fresh contract has one signer (`ContractAdmin`) and threshold 1, and admin entrypoints can be
+//! called directly. Once the set holds more signers or the threshold is raised, every privileged
+//! operation goes through propose -> approve -> execute. Upgrades are additionally timelocked
+//! (see `upgrade`).
+
+mod handover;
+mod pause;
+mod role;
+mod upgrade;
+
+pub use handover::{accept_handover, propose_handover};
+pub use pause::{is_paused, require_not_paused, set_paused};
+pub use role::{grant_role, has_role, require_role_for, revoke_role};
+pub use upgrade::{
+    cancel_upgrade, propose_upgrade, set_upgrade_delay, take_ready_upgrade, upgrade_delay,
+};
+
+use crate::error::ContractError;
+use crate::model::{AdminAction, AdminProposal};
+use crate::storage;
+use soroban_sdk::{panic_with_error, vec, Address, Env, Vec};
+
+/// Max signers; keeps the set a single small instance entry.
+pub const MAX_ADMIN_SIGNERS: u32 = 10;
+
+/// Seconds a proposal stays open for approval and execution (7 days).
+pub const PROPOSAL_LIFETIME: u64 = 7 * 24 * 60 * 60;
+
+/// Current signer set. Contracts that never changed it have just `ContractAdmin`.
+pub fn signers(e: &Env) -> Vec<Address> {
+    match storage::read_admin_signers(e) {
+        Some(signers) => signers,
+        None => vec![e, storage::read_contract_admin(e)],
+    }
+}
+
+pub fn threshold(e: &Env) -> u32 {
+    storage::read_admin_threshold(e)
+}
+
+pub fn is_signer(e: &Env, address: &Address) -> bool {
+    storage::has_contract_admin(e) && signers(e).contains(address)
+}
+
+/// The only signer, if one approval is enough. Such an admin may call admin entrypoints directly.
+pub fn sole_admin(e: &Env) -> Option<Address> {
+    if !storage::has_contract_admin(e) || threshold(e) != 1 {
+        return None;
+    }
+    let signers = signers(e);
+    if signers.len() != 1 {
+        return None;
+    }
+    signers.first()
+}
+
+/// Open a proposal approved by `proposer`. Caller must check the signature.
+pub fn propose(e: &Env, proposer: &Address, action: AdminAction) -> (u32, AdminProposal) {
+    require_signer(e, proposer);
+    let proposal = AdminProposal {
+        action,
+        proposer: proposer.clone(),
+        approvals: vec![e, proposer.clone()],
+        expires_at: e.ledger().timestamp().saturating_add(PROPOSAL_LIFETIME),
+    };
+    let id = storage::next_admin_proposal_id(e);
+    storage::write_admin_proposal(e, id, Some(&proposal));
+    (id, proposal)
+}
+
+/// Add `signer`'s approval to an open proposal. Caller must check the signature.
+pub fn approve(e: &Env, id: u32, signer: &Address) {
+    require_signer(e, signer);
+    let mut proposal = read_open(e, id);
+    if !proposal.approvals.contains(signer) {
+        proposal.approvals.push_back(signer.clone());
+        storage::write_admin_proposal(e, id, Some(&proposal));
+    }
+}
+
+/// Consume a proposal approved by at least `threshold` current signers and return its action.
+pub fn take_approved(e: &Env, id: u32) -> AdminAction {
+    let proposal = read_open(e, id);
+    let signers = signers(e);
+    let approvals = proposal
+        .approvals
+        .iter()
+        .filter(|signer| signers.contains(signer))
+        .count() as u32;
+    if approvals < threshold(e) {
+        panic_with_error!(e, ContractError::ProposalNotApproved)
+    }
+    storage::write_admin_proposal(e, id, None);
+    proposal.action
+}
+
+/// Add a signer. Like every change to the set, this drops any pending contract admin handover,
+/// which would otherwise collapse the new set to 1-of-1 when accepted.
+pub fn add_signer(e: &Env, signer: &Address) {
+    let mut signers = signers(e);
+    if signers.contains(signer) || signers.len() >= MAX_ADMIN_SIGNERS {
+        panic_with_error!(e, ContractError::InvalidAdminConfig)
+    }
+    signers.push_back(signer.clone());
+    write_signers(e, &signers);
+    storage::write_pending_contract_admin(e, None);
+}
+
+/// Remove a signer, dropping any handover it may have proposed. The set may not drop below the threshold.
+pub fn remove_signer(e: &Env, signer: &Address) {
+    let mut signers = signers(e);
+    let index = match signers.first_index_of(signer) {
+        Some(index) => index,
+        None => panic_with_error!(e, ContractError::NotAdminSigner),
+    };
+    signers.remove(index);
+    if signers.len() < threshold(e) {
+        panic_with_error!(e, ContractError::InvalidAdminConfig)
+    }
+    write_signers(e, &signers);
+    storage::write_pending_contract_admin(e, None);
+}
+
+pub fn set_threshold(e: &Env, threshold: u32) {
+    if threshold == 0 || threshold > signers(e).len() {
+        panic_with_error!(e, ContractError::InvalidAdminConfig)
+    }
+    storage::write_admin_threshold(e, &threshold);
+    storage::write_pending_contract_admin(e, None);
+}
+
+/// Replace the whole set with one signer and threshold 1.
+pub fn reset_signers(e: &Env, admin: &Address) {
+    storage::write_admin_threshold(e, &1);
+    write_signers(e, &vec![e, admin.clone()]);
+}
+
+fn require_signer(e: &Env, address: &Address) {
+    if !is_signer(e, address) {
+        panic_with_error!(e, ContractError::NotAdminSigner)
+    }
+}
+
+fn read_open(e: &Env, id: u32) -> AdminProposal {
+    let proposal = match storage::read_admin_proposal(e, id) {
+        Some(proposal) => proposal,
+        None => panic_with_error!(e, ContractError::ProposalNotFound),
+    };
+    if e.ledger().timestamp() > proposal.expires_at {
+        panic_with_error!(e, ContractError::ProposalExpired)
+    }
+    proposal
+}
+
+/// `ContractAdmin` tracks the first signer so legacy readers still see a valid admin.
+fn write_signers(e: &Env, signers: &Vec<Address>) {
+    storage::write_admin_signers(e, signers);
+    storage::write_contract_admin(e, &signers.get_unchecked(0));
+}
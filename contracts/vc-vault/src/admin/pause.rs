@@ -0,0 +1,24 @@
+//! Emergency pause: stop groups of entrypoints without an upgrade.
+
+use crate::error::ContractError;
+use crate::model::PauseScope;
+use crate::storage;
+use soroban_sdk::{panic_with_error, Env, Vec};
+
+pub fn is_paused(e: &Env, scope: PauseScope) -> bool {
+    storage::read_paused(e, scope)
+}
+
+/// Pause (`paused = true`) or resume every scope in `scopes`.
+pub fn set_paused(e: &Env, scopes: &Vec<PauseScope>, paused: bool) {
+    for scope in scopes.iter() {
+        storage::write_paused(e, scope, paused);
+    }
+}
+
+/// Panic with `Paused` if `scope` is paused.
+pub fn require_not_paused(e: &Env, scope: PauseScope) {
+    if is_paused(e, scope) {
+        panic_with_error!(e, ContractError::Paused)
+    }
+}
@@ -0,0 +1,46 @@
+//! Role-based delegation of admin actions. Roles are granted and revoked by the contract admin.
+
+use crate::error::ContractError;
+use crate::model::{AdminAction, Role};
+use crate::storage;
+use soroban_sdk::{panic_with_error, Address, Env};
+
+pub fn has_role(e: &Env, role: Role, account: &Address) -> bool {
+    storage::has_role(e, role, account)
+}
+
+pub fn grant_role(e: &Env, role: Role, account: &Address) {
+    storage::write_role(e, role, account, true);
+}
+
+/// Remove `role` from `account`. Panics if it does not hold it.
+pub fn revoke_role(e: &Env, role: Role, account: &Address) {
+    if !storage::has_role(e, role, account) {
+        panic_with_error!(e, ContractError::MissingRole)
+    }
+    storage::write_role(e, role, account, false);
+}
+
+/// Role allowed to run `action`. None = contract admin only.
+pub fn required_role(action: &AdminAction) -> Option<Role> {
+    match action {
+        AdminAction::SetFeeConfig(..)
+        | AdminAction::SetFeeEnabled(_)
+        | AdminAction::SetFeeAdmin(_)
+        | AdminAction::SetFeeStandard(_)
+        | AdminAction::SetFeeEarly(_) => Some(Role::FeeManager),
+        AdminAction::SetFeeCustom(..) | AdminAction::SetIssuerTier(..) => Some(Role::IssuerCurator),
+        AdminAction::ProposeUpgrade(_) | AdminAction::CancelUpgrade => Some(Role::Upgrader),
+        AdminAction::MigrateIssuance | AdminAction::MigrateRegistry(..) => Some(Role::Migrator),
+        AdminAction::Pause(_) | AdminAction::Unpause(_) => Some(Role::Pauser),
+        _ => None,
+    }
+}
+
+/// Panic unless `account` holds the role `action` requires. Caller must check the signature.
+pub fn require_role_for(e: &Env, account: &Address, action: &AdminAction) {
+    match required_role(action) {
+        Some(role) if has_role(e, role, account) => {}
+        _ => panic_with_error!(e, ContractError::MissingRole),
+    }
+}
@@ -0,0 +1,61 @@
+//! Timelocked contract upgrades: announce a WASM hash, wait out the delay, then swap.
+
+use crate::error::ContractError;
+use crate::model::PendingUpgrade;
+use crate::storage;
+use soroban_sdk::{panic_with_error, BytesN, Env};
+
+/// Shortest allowed upgrade delay (1 day); also the default.
+pub const MIN_UPGRADE_DELAY: u64 = 24 * 60 * 60;
+
+/// Seconds between announcing and executing an upgrade.
+pub fn upgrade_delay(e: &Env) -> u64 {
+    storage::read_upgrade_delay(e).unwrap_or(MIN_UPGRADE_DELAY)
+}
+
+/// Change the delay. Applies to upgrades announced afterwards.
+pub fn set_upgrade_delay(e: &Env, delay: u64) {
+    if delay < MIN_UPGRADE_DELAY {
+        panic_with_error!(e, ContractError::InvalidUpgradeDelay)
+    }
+    storage::write_upgrade_delay(e, &delay);
+}
+
+/// Announce `wasm_hash`. Only one upgrade may be pending at a time.
+pub fn propose_upgrade(e: &Env, wasm_hash: BytesN<32>) -> PendingUpgrade {
+    if storage::read_pending_upgrade(e).is_some() {
+        panic_with_error!(e, ContractError::UpgradeAlreadyPending)
+    }
+    let now = e.ledger().timestamp();
+    let upgrade = PendingUpgrade {
+        wasm_hash,
+        proposed_at: now,
+        executable_at: now.saturating_add(upgrade_delay(e)),
+    };
+    storage::write_pending_upgrade(e, Some(&upgrade));
+    upgrade
+}
+
+/// Drop the pending upgrade and return its hash.
+pub fn cancel_upgrade(e: &Env) -> BytesN<32> {
+    let upgrade = read_pending(e);
+    storage::write_pending_upgrade(e, None);
+    upgrade.wasm_hash
+}
+
+/// Consume the pending upgrade once its delay has passed and return the hash to install.
+pub fn take_ready_upgrade(e: &Env) -> BytesN<32> {
+    let upgrade = read_pending(e);
+    if e.ledger().timestamp() < upgrade.executable_at {
+        panic_with_error!(e, ContractError::UpgradeNotReady)
+    }
+    storage::write_pending_upgrade(e, None);
+    upgrade.wasm_hash
+}
+
+fn read_pending(e: &Env) -> PendingUpgrade {
+    match storage::read_pending_upgrade(e) {
+        Some(upgrade) => upgrade,
+        None => panic_with_error!(e, ContractError::NoUpgradePending),
+    }
+}
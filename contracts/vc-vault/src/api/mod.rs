@@ -2,16 +2,33 @@
 
 use soroban_sdk::{Address, BytesN, Env, Map, String, Vec};
 
-use crate::model::VerifiableCredential;
+use crate::model::{
+    AdminAction, AdminHandover, AdminProposal, FeeTier, GuardianConfig, IssuerAuthorization,
+    IssuerPage, PauseScope, PendingPage, PendingUpgrade, RecoveryRequest, RevocationPolicy,
+    RevocationReason, Role, VCFilter, VCPage, VCTombstone, VerifiableCredential,
+};
 use crate::storage::FeeConfig;
 
 /// Trait defining all public contract entrypoints.
 #[allow(dead_code)]
 pub trait VcVaultTrait {
-    fn initialize(e: Env, contract_admin: Address, default_issuer_did: String);
-    fn set_contract_admin(e: Env, new_admin: Address);
+    fn initialize(
+        e: Env,
+        contract_admin: Address,
+        default_issuer_did: String,
+        deploy_salt: BytesN<32>,
+    );
+    fn propose_contract_admin(e: Env, new_admin: Address);
+    fn accept_contract_admin(e: Env);
+    fn get_pending_contract_admin(e: Env) -> Option<AdminHandover>;
+    fn propose_admin_action(e: Env, signer: Address, action: AdminAction) -> u32;
+    fn approve_admin_action(e: Env, signer: Address, proposal_id: u32);
+    fn execute_admin_action(e: Env, proposal_id: u32);
+    fn get_admin_proposal(e: Env, proposal_id: u32) -> Option<AdminProposal>;
+    fn get_admin_signers(e: Env) -> Vec<Address>;
+    fn get_admin_threshold(e: Env) -> u32;
     fn set_fee_enabled(e: Env, enabled: bool);
-    fn set_fee_config(e: Env, token_contract: Address, fee_dest: Address, fee_amount: i128);
+    fn set_fee_config(e: Env, token_contract: Address, fee_dest: Address);
     fn set_fee_admin(e: Env, fee_amount: i128);
     fn set_fee_standard(e: Env, fee_amount: i128);
     fn set_fee_early(e: Env, fee_amount: i128);
@@ -19,20 +36,76 @@ pub trait VcVaultTrait {
     fn get_fee_admin(e: Env) -> i128;
     fn get_fee_standard(e: Env) -> i128;
     fn get_fee_early(e: Env) -> i128;
-    fn get_fee_custom(e: Env, issuer: Address) -> i128;
-    fn upgrade(e: Env, new_wasm_hash: BytesN<32>);
+    fn get_fee_custom(e: Env, issuer: Address) -> Option<i128>;
+    fn set_issuer_tier(e: Env, issuer: Address, tier: FeeTier);
+    fn get_issuer_tier(e: Env, issuer: Address) -> FeeTier;
+    fn get_issuer_fee(e: Env, issuer: Address) -> i128;
+    fn propose_upgrade(e: Env, new_wasm_hash: BytesN<32>);
+    fn execute_upgrade(e: Env);
+    fn cancel_upgrade(e: Env);
+    fn get_pending_upgrade(e: Env) -> Option<PendingUpgrade>;
+    fn set_upgrade_delay(e: Env, seconds: u64);
+    fn get_upgrade_delay(e: Env) -> u64;
+    fn pause(e: Env, caller: Address, scopes: Vec<PauseScope>);
+    fn unpause(e: Env, scopes: Vec<PauseScope>);
+    fn is_paused(e: Env, scope: PauseScope) -> bool;
+    fn set_pause_guardian(e: Env, guardian: Option<Address>);
+    fn get_pause_guardian(e: Env) -> Option<Address>;
+    fn grant_role(e: Env, role: Role, account: Address);
+    fn revoke_role(e: Env, role: Role, account: Address);
+    fn renounce_role(e: Env, account: Address, role: Role);
+    fn has_role(e: Env, role: Role, account: Address) -> bool;
+    fn execute_role_action(e: Env, caller: Address, action: AdminAction);
     fn version(e: Env) -> String;
     fn fee_config(e: Env) -> FeeConfig;
     fn create_vault(e: Env, owner: Address, did_uri: String);
-    fn set_vault_admin(e: Env, owner: Address, new_admin: Address);
-    fn authorize_issuers(e: Env, owner: Address, issuers: Vec<Address>);
-    fn authorize_issuer(e: Env, owner: Address, issuer: Address);
+    fn propose_vault_admin(e: Env, owner: Address, new_admin: Address);
+    fn accept_vault_admin(e: Env, owner: Address);
+    fn get_pending_vault_admin(e: Env, owner: Address) -> Option<AdminHandover>;
+    fn add_issuers(e: Env, owner: Address, issuers: Vec<Address>);
+    fn authorize_issuer(
+        e: Env,
+        owner: Address,
+        issuer: Address,
+        expires_at: Option<u64>,
+        label: Option<String>,
+        credential_types: Vec<String>,
+    );
     fn revoke_issuer(e: Env, owner: Address, issuer: Address);
+    fn get_issuer_authorization(
+        e: Env,
+        owner: Address,
+        issuer: Address,
+    ) -> Option<IssuerAuthorization>;
+    fn get_issuer_expiry(e: Env, owner: Address, issuer: Address) -> Option<u64>;
+    fn list_issuers(e: Env, owner: Address, cursor: u32, limit: u32) -> IssuerPage;
+    fn set_issuer_quota(e: Env, owner: Address, issuer: Address, quota: Option<u32>);
+    fn set_vault_cap(e: Env, owner: Address, cap: Option<u32>);
+    fn get_remaining_quota(e: Env, owner: Address, issuer: Address) -> Option<u32>;
+    fn set_inbox(e: Env, owner: Address, ttl: Option<u64>);
+    fn list_pending(e: Env, owner: Address, cursor: u32, limit: u32) -> PendingPage;
+    fn accept_vc(e: Env, owner: Address, vc_id: String);
+    fn reject_vc(e: Env, owner: Address, vc_id: String);
     fn revoke_vault(e: Env, owner: Address);
+    fn reactivate_vault(e: Env, owner: Address);
+    fn close_vault(e: Env, owner: Address, limit: u32) -> u32;
+    fn set_reactivation_cooldown(e: Env, seconds: u64);
+    fn get_reactivation_cooldown(e: Env) -> u64;
+    fn set_guardians(e: Env, owner: Address, guardians: Vec<Address>, threshold: u32, delay: u64);
+    fn get_guardians(e: Env, owner: Address) -> Option<GuardianConfig>;
+    fn approve_recovery(e: Env, owner: Address, guardian: Address, new_admin: Address);
+    fn execute_recovery(e: Env, owner: Address);
+    fn cancel_recovery(e: Env, owner: Address);
+    fn get_recoveries(e: Env, owner: Address) -> Vec<RecoveryRequest>;
     fn list_vc_ids(e: Env, owner: Address) -> Vec<String>;
+    fn list_vcs(e: Env, owner: Address, cursor: u32, limit: u32, filter: VCFilter) -> VCPage;
     fn get_vc(e: Env, owner: Address, vc_id: String) -> Option<VerifiableCredential>;
     fn verify_vc(e: Env, owner: Address, vc_id: String) -> Map<String, String>;
-    fn push(e: Env, from_owner: Address, to_owner: Address, vc_id: String, issuer: Address);
+    fn push(e: Env, from_owner: Address, to_owner: Address, vc_id: String);
+    fn delete_vc(e: Env, owner: Address, vc_id: String, tombstone: bool);
+    fn get_vc_tombstone(e: Env, owner: Address, vc_id: String) -> Option<VCTombstone>;
+    fn set_transfer_allowed(e: Env, owner: Address, sender: Address, allowed: bool);
+    fn is_transfer_allowed(e: Env, owner: Address, sender: Address) -> bool;
     fn issue(
         e: Env,
         owner: Address,
@@ -41,8 +114,29 @@ pub trait VcVaultTrait {
         vault_contract: Address,
         issuer: Address,
         issuer_did: String,
-        fee_override: i128,
+        valid_from: Option<u64>,
+        valid_until: Option<u64>,
+        transferable: bool,
     ) -> String;
-    fn revoke(e: Env, vc_id: String, date: String);
+    fn revoke(
+        e: Env,
+        caller: Address,
+        issuer: Address,
+        vc_id: String,
+        reason: RevocationReason,
+        note: Option<String>,
+    );
+    fn suspend(e: Env, caller: Address, issuer: Address, vc_id: String, note: Option<String>);
+    fn reinstate(e: Env, caller: Address, issuer: Address, vc_id: String);
+    fn set_revocation_policy(
+        e: Env,
+        issuer: Address,
+        owner: Option<Address>,
+        policy: RevocationPolicy,
+    );
+    fn get_revocation_policy(e: Env, issuer: Address, owner: Option<Address>) -> RevocationPolicy;
     fn migrate(e: Env, owner: Option<Address>);
+    fn migrate_registry(e: Env, issuer: Address, owner: Address, vc_ids: Vec<String>);
+    fn migrate_vault_index(e: Env, owner: Address);
+    fn migrate_vault_issuers(e: Env, owner: Address);
 }
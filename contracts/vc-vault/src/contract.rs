@@ -1,9 +1,17 @@
 //! Contract implementation: public entrypoints and validation helpers.
 
+use crate::admin;
 use crate::api::VcVaultTrait;
 use crate::error::ContractError;
+use crate::events;
+use crate::fee;
 use crate::issuance;
-use crate::model::VCStatus;
+use crate::model::{
+    AdminAction, AdminHandover, AdminProposal, FeeTier, GuardianConfig, IssuerAuthorization,
+    IssuerPage, PauseScope, PendingPage, PendingUpgrade, RecoveryRequest, RevocationPolicy,
+    RevocationReason, Role, StatusChange, VCFilter, VCPage, VCStatus, VCTombstone,
+    VerifiableCredential,
+};
 use crate::storage;
 use crate::vault;
 use soroban_sdk::{
@@ -27,62 +35,122 @@ pub struct VcVaultContract;
 impl VcVaultTrait for VcVaultContract {
     // --- Global config ---
 
-    fn initialize(e: Env, contract_admin: Address, default_issuer_did: String) {
+    /// Set the contract admin and default issuer DID. Only the account that deployed this
+    /// contract may initialize it: `contract_admin` and `deploy_salt` must derive this contract's
+    /// address, so a front-run `initialize` from another account fails.
+    fn initialize(
+        e: Env,
+        contract_admin: Address,
+        default_issuer_did: String,
+        deploy_salt: BytesN<32>,
+    ) {
         contract_admin.require_auth();
         if storage::has_contract_admin(&e) {
             panic_with_error!(e, ContractError::AlreadyInitialized);
         }
+        let deployed = e
+            .deployer()
+            .with_address(contract_admin.clone(), deploy_salt)
+            .deployed_address();
+        if deployed != e.current_contract_address() {
+            panic_with_error!(e, ContractError::NotDeployer);
+        }
         storage::write_contract_admin(&e, &contract_admin);
         storage::write_default_issuer_did(&e, &default_issuer_did);
         storage::write_fee_enabled(&e, &false);
         storage::extend_instance_ttl(&e);
+        events::initialized(&e, &contract_admin, &default_issuer_did);
+    }
+
+    /// Propose `new_admin` as the contract admin; it takes over once it accepts within 7 days.
+    /// Replaces any earlier proposal. Admin only.
+    fn propose_contract_admin(e: Env, new_admin: Address) {
+        validate_contract_admin(&e);
+        apply_admin_action(&e, AdminAction::ProposeContractAdmin(new_admin));
     }
 
-    /// Set new contract admin. Caller must be current admin.
-    fn set_contract_admin(e: Env, new_admin: Address) {
-        let _ = validate_contract_admin(&e);
-        storage::write_contract_admin(&e, &new_admin);
+    /// Take over the contract admin role as its sole signer. Proposed admin must sign.
+    fn accept_contract_admin(e: Env) {
+        validate_initialized(&e);
+        let new_admin = admin::accept_handover(&e);
         storage::extend_instance_ttl(&e);
+        events::contract_admin_set(&e, &new_admin);
     }
 
-    /// Configure fee: token, destination, amount. Admin only.
-    fn set_fee_config(e: Env, token_contract: Address, fee_dest: Address, fee_amount: i128) {
-        validate_contract_admin(&e);
-        storage::write_fee_token_contract(&e, &token_contract);
-        storage::write_fee_dest(&e, &fee_dest);
-        storage::write_fee_amount(&e, &fee_amount);
+    fn get_pending_contract_admin(e: Env) -> Option<AdminHandover> {
+        storage::read_pending_contract_admin(&e)
+    }
+
+    /// Open a proposal for a privileged operation, approved by `signer`. Returns its ID.
+    fn propose_admin_action(e: Env, signer: Address, action: AdminAction) -> u32 {
+        signer.require_auth();
+        let (id, proposal) = admin::propose(&e, &signer, action);
+        storage::extend_instance_ttl(&e);
+        events::admin_action_proposed(&e, id, &signer, &proposal.action, proposal.expires_at);
+        id
+    }
+
+    /// Add `signer`'s approval to an open proposal.
+    fn approve_admin_action(e: Env, signer: Address, proposal_id: u32) {
+        signer.require_auth();
+        admin::approve(&e, proposal_id, &signer);
+        storage::extend_instance_ttl(&e);
+        events::admin_action_approved(&e, proposal_id, &signer);
+    }
+
+    /// Run a proposal approved by at least `threshold` current signers. Anyone may call.
+    fn execute_admin_action(e: Env, proposal_id: u32) {
+        let action = admin::take_approved(&e, proposal_id);
+        apply_admin_action(&e, action.clone());
+        events::admin_action_executed(&e, proposal_id, &action);
+    }
+
+    fn get_admin_proposal(e: Env, proposal_id: u32) -> Option<AdminProposal> {
+        storage::read_admin_proposal(&e, proposal_id)
+    }
+
+    fn get_admin_signers(e: Env) -> Vec<Address> {
+        validate_initialized(&e);
         storage::extend_instance_ttl(&e);
+        admin::signers(&e)
+    }
+
+    fn get_admin_threshold(e: Env) -> u32 {
+        validate_initialized(&e);
+        storage::extend_instance_ttl(&e);
+        admin::threshold(&e)
+    }
+
+    /// Configure fee: token and destination. Amounts come from the fee tiers. Admin only.
+    fn set_fee_config(e: Env, token_contract: Address, fee_dest: Address) {
+        validate_contract_admin(&e);
+        apply_admin_action(&e, AdminAction::SetFeeConfig(token_contract, fee_dest));
     }
 
     /// Enable or disable fee charging on issue. Admin only.
     fn set_fee_enabled(e: Env, enabled: bool) {
         validate_contract_admin(&e);
-        storage::write_fee_enabled(&e, &enabled);
-        storage::extend_instance_ttl(&e);
+        apply_admin_action(&e, AdminAction::SetFeeEnabled(enabled));
     }
 
     fn set_fee_admin(e: Env, fee_amount: i128) {
         validate_contract_admin(&e);
-        storage::write_fee_admin(&e, &fee_amount);
-        storage::extend_instance_ttl(&e);
+        apply_admin_action(&e, AdminAction::SetFeeAdmin(fee_amount));
     }
 
     fn set_fee_standard(e: Env, fee_amount: i128) {
         validate_contract_admin(&e);
-        storage::write_fee_standard(&e, &fee_amount);
-        storage::extend_instance_ttl(&e);
+        apply_admin_action(&e, AdminAction::SetFeeStandard(fee_amount));
     }
 
     fn set_fee_early(e: Env, fee_amount: i128) {
         validate_contract_admin(&e);
-        storage::write_fee_early(&e, &fee_amount);
-        storage::extend_instance_ttl(&e);
+        apply_admin_action(&e, AdminAction::SetFeeEarly(fee_amount));
     }
 
     fn set_fee_custom(e: Env, issuer: Address, fee_amount: i128) {
         validate_contract_admin(&e);
-        storage::write_fee_custom(&e, &issuer, &fee_amount);
-        storage::extend_instance_ttl(&e);
+        apply_admin_action(&e, AdminAction::SetFeeCustom(issuer, fee_amount));
     }
 
     fn get_fee_admin(e: Env) -> i128 {
@@ -100,18 +168,131 @@ impl VcVaultTrait for VcVaultContract {
         storage::read_fee_early(&e)
     }
 
-    fn get_fee_custom(e: Env, issuer: Address) -> i128 {
+    /// Issuer's custom fee override, if one is set. See get_issuer_fee for the fee actually charged.
+    fn get_fee_custom(e: Env, issuer: Address) -> Option<i128> {
         storage::extend_instance_ttl(&e);
-        storage::read_fee_custom(&e, &issuer)
+        storage::try_read_fee_custom(&e, &issuer)
     }
 
-    /// Upgrade contract WASM. Admin only.
-    fn upgrade(e: Env, new_wasm_hash: BytesN<32>) {
+    /// Assign issuer to a fee tier. Admin only.
+    fn set_issuer_tier(e: Env, issuer: Address, tier: FeeTier) {
         validate_contract_admin(&e);
+        apply_admin_action(&e, AdminAction::SetIssuerTier(issuer, tier));
+    }
+
+    fn get_issuer_tier(e: Env, issuer: Address) -> FeeTier {
+        storage::extend_instance_ttl(&e);
+        storage::read_issuer_tier(&e, &issuer)
+    }
+
+    /// Fee the issuer would be charged per issuance, regardless of whether fees are enabled.
+    fn get_issuer_fee(e: Env, issuer: Address) -> i128 {
         storage::extend_instance_ttl(&e);
+        fee::issuance_fee(&e, &issuer)
+    }
+
+    /// Announce a WASM upgrade, executable once the upgrade delay has passed. Admin only.
+    fn propose_upgrade(e: Env, new_wasm_hash: BytesN<32>) {
+        validate_contract_admin(&e);
+        apply_admin_action(&e, AdminAction::ProposeUpgrade(new_wasm_hash));
+    }
+
+    /// Install the announced WASM once its delay has passed. Anyone may call.
+    fn execute_upgrade(e: Env) {
+        validate_initialized(&e);
+        let new_wasm_hash = admin::take_ready_upgrade(&e);
+        storage::extend_instance_ttl(&e);
+        events::upgraded(&e, &new_wasm_hash);
         e.deployer().update_current_contract_wasm(new_wasm_hash);
     }
 
+    /// Drop the announced upgrade. Admin only.
+    fn cancel_upgrade(e: Env) {
+        validate_contract_admin(&e);
+        apply_admin_action(&e, AdminAction::CancelUpgrade);
+    }
+
+    fn get_pending_upgrade(e: Env) -> Option<PendingUpgrade> {
+        storage::read_pending_upgrade(&e)
+    }
+
+    /// Seconds between `propose_upgrade` and `execute_upgrade`; at least one day. Applies to
+    /// upgrades announced afterwards. Admin only.
+    fn set_upgrade_delay(e: Env, seconds: u64) {
+        validate_contract_admin(&e);
+        apply_admin_action(&e, AdminAction::SetUpgradeDelay(seconds));
+    }
+
+    fn get_upgrade_delay(e: Env) -> u64 {
+        admin::upgrade_delay(&e)
+    }
+
+    /// Stop the entrypoints in `scopes` until `unpause`. Reads keep working. `caller` must sign
+    /// and be the pause guardian, a `Pauser` or the sole contract admin.
+    fn pause(e: Env, caller: Address, scopes: Vec<PauseScope>) {
+        validate_initialized(&e);
+        caller.require_auth();
+        if storage::read_pause_guardian(&e).as_ref() != Some(&caller)
+            && !admin::has_role(&e, Role::Pauser, &caller)
+            && admin::sole_admin(&e).as_ref() != Some(&caller)
+        {
+            panic_with_error!(e, ContractError::NotPauseGuardian)
+        }
+        apply_admin_action(&e, AdminAction::Pause(scopes));
+    }
+
+    /// Resume the entrypoints in `scopes`. Admin only.
+    fn unpause(e: Env, scopes: Vec<PauseScope>) {
+        validate_contract_admin(&e);
+        apply_admin_action(&e, AdminAction::Unpause(scopes));
+    }
+
+    fn is_paused(e: Env, scope: PauseScope) -> bool {
+        admin::is_paused(&e, scope)
+    }
+
+    /// Address that may `pause` without the contract admin. None removes it. Admin only.
+    fn set_pause_guardian(e: Env, guardian: Option<Address>) {
+        validate_contract_admin(&e);
+        apply_admin_action(&e, AdminAction::SetPauseGuardian(guardian));
+    }
+
+    fn get_pause_guardian(e: Env) -> Option<Address> {
+        storage::read_pause_guardian(&e)
+    }
+
+    /// Give `account` a delegated admin role. Admin only.
+    fn grant_role(e: Env, role: Role, account: Address) {
+        validate_contract_admin(&e);
+        apply_admin_action(&e, AdminAction::GrantRole(role, account));
+    }
+
+    /// Take a role away from `account`. Admin only.
+    fn revoke_role(e: Env, role: Role, account: Address) {
+        validate_contract_admin(&e);
+        apply_admin_action(&e, AdminAction::RevokeRole(role, account));
+    }
+
+    /// Give up a role held by `account`. Account must sign.
+    fn renounce_role(e: Env, account: Address, role: Role) {
+        account.require_auth();
+        admin::revoke_role(&e, role, &account);
+        storage::extend_instance_ttl(&e);
+        events::role_revoked(&e, role, &account);
+    }
+
+    fn has_role(e: Env, role: Role, account: Address) -> bool {
+        admin::has_role(&e, role, &account)
+    }
+
+    /// Run an admin action covered by one of `caller`'s roles. Caller must sign.
+    fn execute_role_action(e: Env, caller: Address, action: AdminAction) {
+        validate_initialized(&e);
+        caller.require_auth();
+        admin::require_role_for(&e, &caller, &action);
+        apply_admin_action(&e, action);
+    }
+
     fn version(e: Env) -> String {
         String::from_str(&e, VERSION)
     }
@@ -121,53 +302,203 @@ impl VcVaultTrait for VcVaultContract {
         storage::read_fee_config(&e)
     }
 
+    /// Create owner's vault. Owner must sign. Fails until the contract is initialized.
     fn create_vault(e: Env, owner: Address, did_uri: String) {
+        validate_initialized(&e);
+        admin::require_not_paused(&e, PauseScope::VaultCreation);
         owner.require_auth();
-        if !storage::has_contract_admin(&e) {
-            storage::write_contract_admin(&e, &owner);
-            storage::write_fee_enabled(&e, &false);
-            storage::extend_instance_ttl(&e);
-        }
         if storage::has_vault_admin(&e, &owner) {
             panic_with_error!(e, ContractError::AlreadyInitialized);
         }
+        storage::bump_vault_generation(&e, &owner);
         storage::write_vault_admin(&e, &owner, &owner);
         storage::write_vault_did(&e, &owner, &did_uri);
         storage::write_vault_revoked(&e, &owner, &false);
-        storage::write_vault_issuers(&e, &owner, &Vec::new(&e));
         storage::extend_vault_ttl(&e, &owner);
+        events::vault_created(&e, &owner, &did_uri);
     }
 
-    /// Set vault admin. Current vault admin must sign.
-    fn set_vault_admin(e: Env, owner: Address, new_admin: Address) {
+    /// Propose `new_admin` as owner's vault admin; it takes over once it accepts within 7 days.
+    /// Replaces any earlier proposal. Current vault admin must sign.
+    fn propose_vault_admin(e: Env, owner: Address, new_admin: Address) {
         validate_vault_admin(&e, &owner);
         validate_vault_active(&e, &owner);
-        storage::write_vault_admin(&e, &owner, &new_admin);
+        let handover = vault::propose_admin(&e, &owner, &new_admin);
+        storage::extend_vault_ttl(&e, &owner);
+        events::vault_admin_proposed(&e, &owner, &new_admin, handover.expires_at);
+    }
+
+    /// Take over owner's vault admin role. Proposed admin must sign.
+    fn accept_vault_admin(e: Env, owner: Address) {
+        validate_vault_active(&e, &owner);
+        let new_admin = vault::accept_admin(&e, &owner);
         storage::extend_vault_ttl(&e, &owner);
+        events::vault_admin_set(&e, &owner, &new_admin);
     }
 
-    /// Replace full issuer list. Vault admin only.
-    fn authorize_issuers(e: Env, owner: Address, issuers: Vec<Address>) {
+    fn get_pending_vault_admin(e: Env, owner: Address) -> Option<AdminHandover> {
+        storage::read_pending_vault_admin(&e, &owner)
+    }
+
+    /// Add several issuers without metadata; already-authorized ones are left as is and issuers
+    /// missing from the list stay authorized (use `revoke_issuer`). Vault admin only.
+    fn add_issuers(e: Env, owner: Address, issuers: Vec<Address>) {
         validate_vault_admin(&e, &owner);
         validate_vault_active(&e, &owner);
-        vault::authorize_issuers(&e, &owner, &issuers);
+        vault::add_issuers(&e, &owner, &issuers);
         storage::extend_vault_ttl(&e, &owner);
+        events::issuers_added(&e, &owner, &issuers);
     }
 
-    /// Add single issuer. Vault admin only.
-    fn authorize_issuer(e: Env, owner: Address, issuer_addr: Address) {
+    /// Add single issuer with an optional expiry (ledger timestamp), label and expected
+    /// credential types. The issuer can write until `expires_at` inclusive. Vault admin only.
+    fn authorize_issuer(
+        e: Env,
+        owner: Address,
+        issuer_addr: Address,
+        expires_at: Option<u64>,
+        label: Option<String>,
+        credential_types: Vec<String>,
+    ) {
         validate_vault_admin(&e, &owner);
         validate_vault_active(&e, &owner);
-        vault::authorize_issuer(&e, &owner, &issuer_addr);
+        vault::authorize_issuer(
+            &e,
+            &owner,
+            &issuer_addr,
+            expires_at,
+            label,
+            credential_types,
+        );
         storage::extend_vault_ttl(&e, &owner);
+        events::issuer_authorized(&e, &owner, &issuer_addr);
     }
 
-    /// Remove issuer from list. Vault admin only.
+    /// Remove issuer's authorization. Vault admin only.
     fn revoke_issuer(e: Env, owner: Address, issuer_addr: Address) {
         validate_vault_admin(&e, &owner);
         validate_vault_active(&e, &owner);
         vault::revoke_issuer(&e, &owner, &issuer_addr);
         storage::extend_vault_ttl(&e, &owner);
+        events::issuer_revoked(&e, &owner, &issuer_addr);
+    }
+
+    /// Get issuer's authorization record in owner's vault. None if not authorized.
+    fn get_issuer_authorization(
+        e: Env,
+        owner: Address,
+        issuer_addr: Address,
+    ) -> Option<IssuerAuthorization> {
+        storage::extend_vault_issuer_ttl(&e, &owner, &issuer_addr);
+        storage::read_vault_issuer(&e, &owner, &issuer_addr)
+    }
+
+    /// When issuer's authorization in owner's vault lapses. None = open-ended.
+    /// Panics if the issuer was never authorized (or was revoked).
+    fn get_issuer_expiry(e: Env, owner: Address, issuer_addr: Address) -> Option<u64> {
+        match storage::read_vault_issuer(&e, &owner, &issuer_addr) {
+            Some(auth) => {
+                storage::extend_vault_issuer_ttl(&e, &owner, &issuer_addr);
+                auth.expires_at
+            }
+            None => panic_with_error!(e, ContractError::IssuerNotAuthorized),
+        }
+    }
+
+    /// Limit how many credentials `issuer_addr` may hold in owner's vault. None = unlimited.
+    /// Only credentials written after the quota feature shipped are counted. Vault admin only.
+    fn set_issuer_quota(e: Env, owner: Address, issuer_addr: Address, quota: Option<u32>) {
+        validate_vault_admin(&e, &owner);
+        validate_vault_active(&e, &owner);
+        storage::write_issuer_quota(&e, &owner, &issuer_addr, quota);
+        storage::extend_vault_ttl(&e, &owner);
+        events::issuer_quota_set(&e, &owner, &issuer_addr, quota);
+    }
+
+    /// Limit total credentials in owner's vault. None = unlimited. Vault admin only.
+    fn set_vault_cap(e: Env, owner: Address, cap: Option<u32>) {
+        validate_vault_admin(&e, &owner);
+        validate_vault_active(&e, &owner);
+        storage::write_vault_cap(&e, &owner, cap);
+        storage::extend_vault_ttl(&e, &owner);
+        events::vault_cap_set(&e, &owner, cap);
+    }
+
+    /// How many more credentials `issuer_addr` may issue into owner's vault, counting pending inbox
+    /// items. None = unlimited.
+    fn get_remaining_quota(e: Env, owner: Address, issuer_addr: Address) -> Option<u32> {
+        validate_vault_initialized(&e, &owner);
+        vault::remaining_quota(&e, &owner, &issuer_addr)
+    }
+
+    /// Remove VC payload and index entry from owner's vault; the issuer's status registry entry
+    /// is kept. `tombstone` leaves a marker that blocks the ID from coming back. Vault admin only.
+    fn delete_vc(e: Env, owner: Address, vc_id: String, tombstone: bool) {
+        validate_vault_admin(&e, &owner);
+        validate_vault_active(&e, &owner);
+        vault::delete_vc(&e, &owner, &vc_id, tombstone);
+        storage::extend_vault_ttl(&e, &owner);
+        events::vc_deleted(&e, &owner, &vc_id, tombstone);
+    }
+
+    /// Tombstone left by `delete_vc`, if any.
+    fn get_vc_tombstone(e: Env, owner: Address, vc_id: String) -> Option<VCTombstone> {
+        storage::read_vc_tombstone(&e, &owner, &vc_id)
+    }
+
+    /// Let `sender` push credentials into owner's vault without the vault admin signing each
+    /// transfer, or withdraw that permission. Vault admin only.
+    fn set_transfer_allowed(e: Env, owner: Address, sender: Address, allowed: bool) {
+        validate_vault_admin(&e, &owner);
+        validate_vault_active(&e, &owner);
+        storage::write_transfer_allowed(&e, &owner, &sender, allowed);
+        storage::extend_vault_ttl(&e, &owner);
+        events::transfer_allowed_set(&e, &owner, &sender, allowed);
+    }
+
+    /// True if owner accepts pushes from `sender` without the vault admin signing.
+    fn is_transfer_allowed(e: Env, owner: Address, sender: Address) -> bool {
+        storage::has_transfer_allowed(&e, &owner, &sender)
+    }
+
+    /// Hold incoming credentials (from `issue` and `push`) for the holder's decision; each stays
+    /// acceptable for `ttl` seconds. None turns the inbox off. Vault admin only.
+    fn set_inbox(e: Env, owner: Address, ttl: Option<u64>) {
+        validate_vault_admin(&e, &owner);
+        validate_vault_active(&e, &owner);
+        storage::write_inbox_ttl(&e, &owner, ttl);
+        storage::extend_vault_ttl(&e, &owner);
+        events::inbox_set(&e, &owner, ttl);
+    }
+
+    /// Page through owner's pending credentials. Start with cursor 0; at most 50 per page.
+    fn list_pending(e: Env, owner: Address, cursor: u32, limit: u32) -> PendingPage {
+        storage::extend_vault_ttl(&e, &owner);
+        vault::list_pending(&e, &owner, cursor, limit)
+    }
+
+    /// Move a pending credential into the vault. Vault admin only.
+    fn accept_vc(e: Env, owner: Address, vc_id: String) {
+        validate_vault_admin(&e, &owner);
+        validate_vault_active(&e, &owner);
+        let vc = vault::accept_vc(&e, &owner, &vc_id);
+        storage::extend_vault_ttl(&e, &owner);
+        storage::extend_vc_ttl(&e, &owner, &vc.issuer, &vc_id);
+        events::vc_accepted(&e, &owner, &vc_id);
+    }
+
+    /// Discard a pending credential; a pushed one returns to its sender. Vault admin only.
+    fn reject_vc(e: Env, owner: Address, vc_id: String) {
+        validate_vault_admin(&e, &owner);
+        let returned_to = vault::reject_vc(&e, &owner, &vc_id);
+        storage::extend_vault_ttl(&e, &owner);
+        events::vc_rejected(&e, &owner, &vc_id, returned_to);
+    }
+
+    /// Page through owner's issuer authorizations. Start with cursor 0; at most 50 per page.
+    fn list_issuers(e: Env, owner: Address, cursor: u32, limit: u32) -> IssuerPage {
+        storage::extend_vault_ttl(&e, &owner);
+        vault::list_issuers(&e, &owner, cursor, limit)
     }
 
     /// Revoke vault. Blocks all writes. Vault admin only.
@@ -175,30 +506,122 @@ impl VcVaultTrait for VcVaultContract {
         validate_vault_admin(&e, &owner);
         validate_vault_active(&e, &owner);
         storage::write_vault_revoked(&e, &owner, &true);
+        storage::write_vault_revoked_at(&e, &owner, &e.ledger().timestamp());
         storage::extend_vault_ttl(&e, &owner);
+        events::vault_revoked(&e, &owner);
     }
 
-    /// List VC IDs in owner's vault.
+    /// Unfreeze a revoked vault after the contract's reactivation cooldown. Vault admin only.
+    fn reactivate_vault(e: Env, owner: Address) {
+        validate_vault_admin(&e, &owner);
+        vault::reactivate(&e, &owner);
+        storage::extend_vault_ttl(&e, &owner);
+        events::vault_reactivated(&e, &owner);
+    }
+
+    /// Freeze the vault and delete up to `limit` of its pending items, credentials and issuer
+    /// entries (at most 50 per call); the final call also deletes the vault metadata. Returns
+    /// entries left; repeat until 0. Status registry entries are kept. Vaults with a legacy index
+    /// must run `migrate_vault_index` and `migrate_vault_issuers` first. Vault admin only.
+    fn close_vault(e: Env, owner: Address, limit: u32) -> u32 {
+        validate_vault_admin(&e, &owner);
+        if !storage::read_vault_revoked(&e, &owner) {
+            storage::write_vault_revoked(&e, &owner, &true);
+            storage::write_vault_revoked_at(&e, &owner, &e.ledger().timestamp());
+        }
+        let remaining = vault::close_batch(&e, &owner, limit);
+        if remaining > 0 {
+            storage::extend_vault_ttl(&e, &owner);
+        }
+        events::vault_close_progress(&e, &owner, remaining);
+        remaining
+    }
+
+    /// Seconds a revoked vault must wait before `reactivate_vault`. Admin only.
+    fn set_reactivation_cooldown(e: Env, seconds: u64) {
+        validate_contract_admin(&e);
+        apply_admin_action(&e, AdminAction::SetReactivationCooldown(seconds));
+    }
+
+    fn get_reactivation_cooldown(e: Env) -> u64 {
+        storage::read_reactivation_cooldown(&e)
+    }
+
+    /// Register guardians that can jointly rotate the vault admin: `threshold` approvals start a
+    /// `delay` (seconds, at least 1 day) after which anyone can execute it. Empty list removes guardians.
+    /// Cancels any pending recovery. Vault admin only.
+    fn set_guardians(e: Env, owner: Address, guardians: Vec<Address>, threshold: u32, delay: u64) {
+        validate_vault_admin(&e, &owner);
+        vault::set_guardians(&e, &owner, guardians.clone(), threshold, delay);
+        storage::extend_vault_ttl(&e, &owner);
+        events::guardians_set(&e, &owner, &guardians, threshold, delay);
+    }
+
+    fn get_guardians(e: Env, owner: Address) -> Option<GuardianConfig> {
+        storage::read_guardians(&e, &owner)
+    }
+
+    /// Approve rotating owner's vault admin to `new_admin`. Guardian must sign. Approvals are
+    /// counted per `new_admin`; a guardian's latest approval replaces its earlier one.
+    fn approve_recovery(e: Env, owner: Address, guardian: Address, new_admin: Address) {
+        validate_vault_initialized(&e, &owner);
+        guardian.require_auth();
+        let request = vault::approve_recovery(&e, &owner, &guardian, &new_admin);
+        storage::extend_vault_ttl(&e, &owner);
+        events::recovery_approved(&e, &owner, &guardian, &new_admin, request.executable_at);
+    }
+
+    /// Install the recovered admin once approved and the delay has passed. Anyone may call.
+    fn execute_recovery(e: Env, owner: Address) {
+        validate_vault_initialized(&e, &owner);
+        let new_admin = vault::execute_recovery(&e, &owner);
+        storage::extend_vault_ttl(&e, &owner);
+        events::recovery_executed(&e, &owner, &new_admin);
+    }
+
+    /// Cancel a pending recovery. Current vault admin only.
+    fn cancel_recovery(e: Env, owner: Address) {
+        validate_vault_admin(&e, &owner);
+        vault::cancel_recovery(&e, &owner);
+        storage::extend_vault_ttl(&e, &owner);
+        events::recovery_cancelled(&e, &owner);
+    }
+
+    fn get_recoveries(e: Env, owner: Address) -> Vec<RecoveryRequest> {
+        storage::read_recoveries(&e, &owner)
+    }
+
+    /// List all VC IDs in owner's vault. Reads the whole index; use `list_vcs` for large vaults.
+    /// Index order is oldest first, except that each removal moves the newest ID into the freed
+    /// position.
     fn list_vc_ids(e: Env, owner: Address) -> Vec<String> {
         storage::extend_vault_ttl(&e, &owner);
-        storage::read_vault_vc_ids(&e, &owner)
+        vault::list_vc_ids(&e, &owner)
+    }
+
+    /// Page through owner's VCs from `cursor` (0 = start), up to `limit` (capped at 50) matching
+    /// `filter`. Pass the returned `next_cursor` to continue; pages may be short when filtering.
+    /// Cursors are index positions and are not stable across writes: a removal between calls
+    /// moves the newest ID into an earlier position, where a walk already past it will miss it.
+    fn list_vcs(e: Env, owner: Address, cursor: u32, limit: u32, filter: VCFilter) -> VCPage {
+        storage::extend_vault_ttl(&e, &owner);
+        vault::list_vcs(&e, &owner, cursor, limit, &filter)
     }
 
     /// Get VC payload by ID. Returns None if not found.
-    fn get_vc(
-        e: Env,
-        owner: Address,
-        vc_id: String,
-    ) -> Option<crate::model::VerifiableCredential> {
+    fn get_vc(e: Env, owner: Address, vc_id: String) -> Option<VerifiableCredential> {
         storage::extend_vault_ttl(&e, &owner);
         let vc = storage::read_vault_vc(&e, &owner, &vc_id);
-        if vc.is_some() {
-            storage::extend_vc_ttl(&e, &owner, &vc_id);
+        if let Some(vc) = &vc {
+            storage::extend_vc_ttl(&e, &owner, &vc.issuer, &vc_id);
         }
         vc
     }
 
-    /// Verify VC status. Returns map with "status" (valid/revoked/invalid) and optionally "since".
+    /// Verify VC status. Returns map with "status"
+    /// (valid/revoked/suspended/expired/not_yet_valid/invalid)
+    /// and, depending on status, "since"/"since_ledger"/"note"/"reason" or "valid_from".
+    /// Stored VCs also get "transferable" ("true"/"false").
     fn verify_vc(e: Env, owner: Address, vc_id: String) -> Map<String, String> {
         storage::extend_vault_ttl(&e, &owner);
         let vc_opt = storage::read_vault_vc(&e, &owner, &vc_id);
@@ -206,47 +629,74 @@ impl VcVaultTrait for VcVaultContract {
             return issuance_status_to_map(&e, VCStatus::Invalid);
         }
         let vc = vc_opt.unwrap();
-        storage::extend_vc_ttl(&e, &owner, &vc_id);
-        let issuance_contract = vc.issuance_contract;
-        if issuance_contract == e.current_contract_address() {
-            let status = storage::read_vc_status(&e, &vc_id);
-            return issuance_status_to_map(&e, status);
-        }
-        e.invoke_contract::<Map<String, String>>(
-            &issuance_contract,
-            &symbol_short!("verify"),
-            (vc_id,).into_val(&e),
-        )
+        storage::extend_vc_ttl(&e, &owner, &vc.issuer, &vc_id);
+        let issuance_contract = vc.issuance_contract.clone();
+        let mut map = if issuance_contract == e.current_contract_address() {
+            let status = storage::read_vc_status(&e, &vc.issuer, &vc_id);
+            issuance_status_to_map(&e, issuance::effective_status(&e, &vc, status))
+        } else {
+            e.invoke_contract::<Map<String, String>>(
+                &issuance_contract,
+                &symbol_short!("verify"),
+                (vc_id,).into_val(&e),
+            )
+        };
+        let transferable = if vc.transferable { "true" } else { "false" };
+        map.set(
+            String::from_str(&e, "transferable"),
+            String::from_str(&e, transferable),
+        );
+        map
     }
 
-    /// Move VC from one vault to another. From-owner must sign. Issuer must be authorized in source.
-    fn push(e: Env, from_owner: Address, to_owner: Address, vc_id: String, issuer_addr: Address) {
+    /// Move VC from one vault to another. From-owner must sign. The recipient's vault admin
+    /// must also sign unless the recipient allows transfers from `from_owner`.
+    fn push(e: Env, from_owner: Address, to_owner: Address, vc_id: String) {
+        admin::require_not_paused(&e, PauseScope::Transfers);
         validate_vault_active(&e, &from_owner);
         validate_vault_active(&e, &to_owner);
         validate_vault_initialized(&e, &from_owner);
         validate_vault_initialized(&e, &to_owner);
         from_owner.require_auth();
-        validate_issuer_authorized_only(&e, &from_owner, &issuer_addr);
+        if !storage::has_transfer_allowed(&e, &to_owner, &from_owner) {
+            storage::read_vault_admin(&e, &to_owner).require_auth();
+        }
 
         let vc_opt = storage::read_vault_vc(&e, &from_owner, &vc_id);
         if vc_opt.is_none() {
             panic_with_error!(e, ContractError::VCNotFound);
         }
         let vc = vc_opt.unwrap();
+        if !vc.transferable {
+            panic_with_error!(e, ContractError::VCNotTransferable);
+        }
+        vault::check_quota(&e, &to_owner, &vc.issuer);
+        if storage::has_vault_vc(&e, &to_owner, &vc_id)
+            || storage::has_pending_vc(&e, &to_owner, &vc_id)
+            || storage::has_vc_tombstone(&e, &to_owner, &vc_id)
+        {
+            panic_with_error!(e, ContractError::VCAlreadyExists);
+        }
 
-        storage::remove_vault_vc(&e, &from_owner, &vc_id);
-        storage::remove_vault_vc_id(&e, &from_owner, &vc_id);
-        storage::write_vault_vc(&e, &to_owner, &vc_id, &vc);
-        storage::append_vault_vc_id(&e, &to_owner, &vc_id);
+        vault::remove_vc(&e, &from_owner, &vc);
+        let pending_until = vault::deliver(&e, &to_owner, &vc, Some(from_owner.clone()));
 
         storage::extend_vault_ttl(&e, &from_owner);
         storage::extend_vault_ttl(&e, &to_owner);
-        storage::extend_vc_ttl(&e, &to_owner, &vc_id);
+        storage::extend_vc_ttl(&e, &to_owner, &vc.issuer, &vc_id);
+        events::vc_pushed(&e, &from_owner, &to_owner, &vc_id);
+        if let Some(expires_at) = pending_until {
+            events::vc_pending(&e, &to_owner, &vc_id, expires_at);
+        }
     }
 
     // --- Issuance ---
 
     /// Issue VC: store in vault, set status Valid. Issuer must sign and be authorized.
+    /// The ID must be unused by this issuer and absent from the vault.
+    /// Charges the issuer's tiered fee when fees are enabled.
+    /// Optional `valid_from` / `valid_until` bound validity in ledger timestamp seconds.
+    /// `transferable = false` binds the VC to this vault: `push` rejects it.
     fn issue(
         e: Env,
         owner: Address,
@@ -255,8 +705,11 @@ impl VcVaultTrait for VcVaultContract {
         vault_contract: Address,
         issuer_addr: Address,
         issuer_did: String,
-        fee_override: i128,
+        valid_from: Option<u64>,
+        valid_until: Option<u64>,
+        transferable: bool,
     ) -> String {
+        admin::require_not_paused(&e, PauseScope::Issuance);
         issuer_addr.require_auth();
         let this = e.current_contract_address();
         if vault_contract != this {
@@ -265,8 +718,19 @@ impl VcVaultTrait for VcVaultContract {
         validate_vault_active(&e, &owner);
         validate_vault_initialized(&e, &owner);
         validate_issuer_authorized_only(&e, &owner, &issuer_addr);
+        vault::check_quota(&e, &owner, &issuer_addr);
+        issuance::validate_window(&e, valid_from, valid_until);
+        if storage::read_vc_status(&e, &issuer_addr, &vc_id) != VCStatus::Invalid
+            || storage::has_vault_vc(&e, &owner, &vc_id)
+            || storage::has_pending_vc(&e, &owner, &vc_id)
+            || storage::has_vc_tombstone(&e, &owner, &vc_id)
+        {
+            panic_with_error!(e, ContractError::VCAlreadyExists);
+        }
 
-        store_vc_payload(
+        // Registered first, so storing it records the owner once it lands in the vault.
+        storage::write_vc_status(&e, &issuer_addr, &vc_id, &VCStatus::Valid);
+        let pending_until = store_vc_payload(
             &e,
             &owner,
             vc_id.clone(),
@@ -274,86 +738,352 @@ impl VcVaultTrait for VcVaultContract {
             &issuer_addr,
             issuer_did,
             this.clone(),
-            fee_override,
+            valid_from,
+            valid_until,
+            transferable,
         );
 
-        storage::write_vc_status(&e, &vc_id, &VCStatus::Valid);
-        storage::write_vc_owner(&e, &vc_id, &owner);
         storage::extend_vault_ttl(&e, &owner);
-        storage::extend_vc_ttl(&e, &owner, &vc_id);
+        storage::extend_vc_ttl(&e, &owner, &issuer_addr, &vc_id);
+        events::vc_issued(&e, &owner, &vc_id, &issuer_addr);
+        if let Some(expires_at) = pending_until {
+            events::vc_pending(&e, &owner, &vc_id, expires_at);
+        }
 
         vc_id
     }
 
-    /// Revoke VC. Owner or contract admin must sign.
-    fn revoke(e: Env, vc_id: String, date: String) {
-        validate_vc_exists(&e, &vc_id);
-        match storage::read_vc_owner(&e, &vc_id) {
-            Some(owner) => owner.require_auth(),
-            None => {
-                let _ = validate_contract_admin(&e);
+    /// Revoke VC issued by `issuer_addr` at the current ledger time with a reason code.
+    /// `note` is informational only. `caller` must sign and be allowed by the revocation policy.
+    fn revoke(
+        e: Env,
+        caller: Address,
+        issuer_addr: Address,
+        vc_id: String,
+        reason: RevocationReason,
+        note: Option<String>,
+    ) {
+        validate_status_authority(&e, &caller, &issuer_addr, &vc_id);
+        revoke_status(&e, &issuer_addr, vc_id, reason, note);
+    }
+
+    /// Suspend VC issued by `issuer_addr` until reinstated. Same authorization as `revoke`.
+    fn suspend(e: Env, caller: Address, issuer_addr: Address, vc_id: String, note: Option<String>) {
+        validate_status_authority(&e, &caller, &issuer_addr, &vc_id);
+        suspend_status(&e, &issuer_addr, vc_id, note);
+    }
+
+    /// Lift a suspension. Same authorization as `revoke`.
+    fn reinstate(e: Env, caller: Address, issuer_addr: Address, vc_id: String) {
+        validate_status_authority(&e, &caller, &issuer_addr, &vc_id);
+        reinstate_status(&e, &issuer_addr, vc_id);
+    }
+
+    /// Set who may change status of VCs written by `issuer_addr`. Issuer must sign.
+    /// `owner` None = issuer-wide default; Some(owner) = override for that vault, which the
+    /// vault admin must also sign.
+    fn set_revocation_policy(
+        e: Env,
+        issuer_addr: Address,
+        owner: Option<Address>,
+        policy: RevocationPolicy,
+    ) {
+        issuer_addr.require_auth();
+        match &owner {
+            Some(owner) => {
+                validate_vault_admin(&e, owner);
+                storage::write_vault_revocation_policy(&e, &issuer_addr, owner, &policy);
             }
+            None => storage::write_revocation_policy(&e, &issuer_addr, &policy),
         }
-        issuance::revoke_vc(&e, vc_id.clone(), date);
-        storage::extend_vc_status_ttl(&e, &vc_id);
+        events::revocation_policy_set(&e, &issuer_addr, &owner, policy);
+    }
+
+    /// Effective policy for VCs written by `issuer_addr` (into `owner`'s vault, if given).
+    fn get_revocation_policy(
+        e: Env,
+        issuer_addr: Address,
+        owner: Option<Address>,
+    ) -> RevocationPolicy {
+        issuance::revocation_policy(&e, &issuer_addr, owner.as_ref())
     }
 
     // --- Migrations ---
 
     /// Migrate legacy storage. Some(owner) = vault migration; None = issuance registry migration.
     fn migrate(e: Env, owner: Option<Address>) {
-        match owner {
+        match owner.clone() {
             Some(owner) => {
                 validate_vault_admin(&e, &owner);
                 let vcs = storage::read_legacy_vault_vcs(&e, &owner);
                 if vcs.is_none() {
                     panic_with_error!(e, ContractError::VCSAlreadyMigrated)
                 }
+                // Legacy issuance contracts were deployed per issuer, so they scope the registry.
+                // The holder already has these credentials, so they skip the inbox.
                 for vc in vcs.unwrap().iter() {
-                    vault::store_vc(
+                    vault::insert_vc(
                         &e,
                         &owner,
-                        vc.id.clone(),
-                        vc.data.clone(),
-                        vc.issuance_contract.clone(),
-                        vc.issuer_did.clone(),
+                        &VerifiableCredential {
+                            id: vc.id.clone(),
+                            data: vc.data.clone(),
+                            issuance_contract: vc.issuance_contract.clone(),
+                            issuer: vc.issuance_contract.clone(),
+                            issuer_did: vc.issuer_did.clone(),
+                            valid_from: None,
+                            valid_until: None,
+                            transferable: true,
+                        },
                     );
                 }
                 storage::remove_legacy_vault_vcs(&e, &owner);
                 storage::extend_vault_ttl(&e, &owner);
+                events::migrated(&e, &Some(owner));
             }
             None => {
                 validate_contract_admin(&e);
-                storage::extend_instance_ttl(&e);
-                let vcs = storage::read_legacy_issuance_vcs(&e);
-                if vcs.is_none() {
-                    panic_with_error!(e, ContractError::VCSAlreadyMigrated)
-                }
-                let revocations = storage::read_legacy_issuance_revocations(&e);
-                for vc_id in vcs.unwrap().iter() {
-                    match revocations.get(vc_id.clone()) {
-                        Some(revocation) => {
-                            storage::write_vc_status(&e, &vc_id.clone(), &VCStatus::Revoked(revocation.date))
-                        }
-                        None => storage::write_vc_status(&e, &vc_id, &VCStatus::Valid),
-                    }
-                    storage::extend_vc_status_ttl(&e, &vc_id);
-                }
-                storage::remove_legacy_issuance_vcs(&e);
-                storage::remove_legacy_issuance_revocations(&e);
+                apply_admin_action(&e, AdminAction::MigrateIssuance);
+            }
+        }
+    }
+
+    /// Move pre-scoping registry entries under `issuer_addr`. Contract admin only.
+    fn migrate_registry(e: Env, issuer_addr: Address, owner: Address, vc_ids: Vec<String>) {
+        validate_contract_admin(&e);
+        apply_admin_action(&e, AdminAction::MigrateRegistry(issuer_addr, owner, vc_ids));
+    }
+
+    /// Move owner's single-vector VC index into the paged index, dropping IDs whose payload is
+    /// gone. The first write to the vault does the same. Vault admin only.
+    fn migrate_vault_index(e: Env, owner: Address) {
+        validate_vault_admin(&e, &owner);
+        let moved = match vault::migrate_legacy_index(&e, &owner) {
+            Some(moved) => moved,
+            None => panic_with_error!(e, ContractError::VCSAlreadyMigrated),
+        };
+        storage::extend_vault_ttl(&e, &owner);
+        events::vault_index_migrated(&e, &owner, moved);
+    }
+
+    /// Move owner's single-vector issuer list into per-issuer entries. Vault admin only.
+    fn migrate_vault_issuers(e: Env, owner: Address) {
+        validate_vault_admin(&e, &owner);
+        let count = vault::migrate_issuers(&e, &owner);
+        storage::extend_vault_ttl(&e, &owner);
+        events::vault_issuers_migrated(&e, &owner, count);
+    }
+}
+
+// --- Admin actions ---
+
+/// Perform a privileged operation. Callers have already established admin or role authority.
+fn apply_admin_action(e: &Env, action: AdminAction) {
+    storage::extend_instance_ttl(e);
+    match action {
+        AdminAction::SetFeeConfig(token_contract, fee_dest) => {
+            storage::write_fee_token_contract(e, &token_contract);
+            storage::write_fee_dest(e, &fee_dest);
+            storage::remove_legacy_fee_amount(e);
+            events::fee_config_set(e, &token_contract, &fee_dest);
+        }
+        AdminAction::SetFeeEnabled(enabled) => {
+            storage::write_fee_enabled(e, &enabled);
+            events::fee_enabled_set(e, enabled);
+        }
+        AdminAction::SetFeeAdmin(fee_amount) => {
+            storage::write_fee_admin(e, &fee_amount);
+            events::fee_tier_set(e, symbol_short!("admin"), fee_amount);
+        }
+        AdminAction::SetFeeStandard(fee_amount) => {
+            storage::write_fee_standard(e, &fee_amount);
+            events::fee_tier_set(e, symbol_short!("standard"), fee_amount);
+        }
+        AdminAction::SetFeeEarly(fee_amount) => {
+            storage::write_fee_early(e, &fee_amount);
+            events::fee_tier_set(e, symbol_short!("early"), fee_amount);
+        }
+        AdminAction::SetFeeCustom(issuer, fee_amount) => {
+            storage::write_fee_custom(e, &issuer, &fee_amount);
+            events::fee_custom_set(e, &issuer, fee_amount);
+        }
+        AdminAction::SetIssuerTier(issuer, tier) => {
+            storage::write_issuer_tier(e, &issuer, &tier);
+            events::issuer_tier_set(e, &issuer, tier);
+        }
+        AdminAction::SetReactivationCooldown(seconds) => {
+            storage::write_reactivation_cooldown(e, &seconds);
+            events::reactivation_cooldown_set(e, seconds);
+        }
+        AdminAction::ProposeUpgrade(new_wasm_hash) => {
+            let upgrade = admin::propose_upgrade(e, new_wasm_hash);
+            events::upgrade_proposed(e, &upgrade.wasm_hash, upgrade.executable_at);
+        }
+        AdminAction::CancelUpgrade => {
+            let wasm_hash = admin::cancel_upgrade(e);
+            events::upgrade_cancelled(e, &wasm_hash);
+        }
+        AdminAction::SetUpgradeDelay(seconds) => {
+            admin::set_upgrade_delay(e, seconds);
+            events::upgrade_delay_set(e, seconds);
+        }
+        AdminAction::ProposeContractAdmin(new_admin) => {
+            let handover = admin::propose_handover(e, &new_admin);
+            events::contract_admin_proposed(e, &new_admin, handover.expires_at);
+        }
+        AdminAction::Pause(scopes) => {
+            admin::set_paused(e, &scopes, true);
+            events::paused(e, &scopes);
+        }
+        AdminAction::Unpause(scopes) => {
+            admin::set_paused(e, &scopes, false);
+            events::unpaused(e, &scopes);
+        }
+        AdminAction::SetPauseGuardian(guardian) => {
+            storage::write_pause_guardian(e, guardian.as_ref());
+            events::pause_guardian_set(e, &guardian);
+        }
+        AdminAction::GrantRole(role, account) => {
+            admin::grant_role(e, role, &account);
+            events::role_granted(e, role, &account);
+        }
+        AdminAction::RevokeRole(role, account) => {
+            admin::revoke_role(e, role, &account);
+            events::role_revoked(e, role, &account);
+        }
+        AdminAction::MigrateIssuance => {
+            migrate_issuance_registry(e);
+            events::migrated(e, &None);
+        }
+        AdminAction::MigrateRegistry(issuer_addr, owner, vc_ids) => {
+            migrate_registry_entries(e, &issuer_addr, &owner, &vc_ids)
+        }
+        AdminAction::AddSigner(signer) => {
+            admin::add_signer(e, &signer);
+            events::admin_signers_set(e, &admin::signers(e), admin::threshold(e));
+        }
+        AdminAction::RemoveSigner(signer) => {
+            admin::remove_signer(e, &signer);
+            events::admin_signers_set(e, &admin::signers(e), admin::threshold(e));
+        }
+        AdminAction::SetThreshold(threshold) => {
+            admin::set_threshold(e, threshold);
+            events::admin_signers_set(e, &admin::signers(e), threshold);
+        }
+        AdminAction::Revoke(issuer_addr, vc_id, reason) => {
+            validate_admin_status_change(e, &issuer_addr, &vc_id);
+            revoke_status(e, &issuer_addr, vc_id, reason, None);
+        }
+        AdminAction::Suspend(issuer_addr, vc_id) => {
+            validate_admin_status_change(e, &issuer_addr, &vc_id);
+            suspend_status(e, &issuer_addr, vc_id, None);
+        }
+        AdminAction::Reinstate(issuer_addr, vc_id) => {
+            validate_admin_status_change(e, &issuer_addr, &vc_id);
+            reinstate_status(e, &issuer_addr, vc_id);
+        }
+    }
+}
+
+fn revoke_status(
+    e: &Env,
+    issuer_addr: &Address,
+    vc_id: String,
+    reason: RevocationReason,
+    note: Option<String>,
+) {
+    let change = issuance::revoke_vc(e, issuer_addr, vc_id.clone(), reason, note);
+    storage::extend_vc_status_ttl(e, issuer_addr, &vc_id);
+    events::vc_revoked(e, issuer_addr, &vc_id, &change, reason);
+}
+
+fn suspend_status(e: &Env, issuer_addr: &Address, vc_id: String, note: Option<String>) {
+    let change = issuance::suspend_vc(e, issuer_addr, vc_id.clone(), note);
+    storage::extend_vc_status_ttl(e, issuer_addr, &vc_id);
+    events::vc_suspended(e, issuer_addr, &vc_id, &change);
+}
+
+fn reinstate_status(e: &Env, issuer_addr: &Address, vc_id: String) {
+    issuance::reinstate_vc(e, issuer_addr, vc_id.clone());
+    storage::extend_vc_status_ttl(e, issuer_addr, &vc_id);
+    events::vc_reinstated(e, issuer_addr, &vc_id);
+}
+
+/// Convert the legacy issuance registry (vector of IDs + revocation map) into registry entries.
+fn migrate_issuance_registry(e: &Env) {
+    let vcs = storage::read_legacy_issuance_vcs(e);
+    if vcs.is_none() {
+        panic_with_error!(e, ContractError::VCSAlreadyMigrated)
+    }
+    let revocations = storage::read_legacy_issuance_revocations(e);
+    let this = e.current_contract_address();
+    for vc_id in vcs.unwrap().iter() {
+        match revocations.get(vc_id.clone()) {
+            Some(revocation) => {
+                storage::write_vc_status(e, &this, &vc_id, &storage::legacy_revocation(revocation.date))
             }
+            None => storage::write_vc_status(e, &this, &vc_id, &VCStatus::Valid),
         }
+        storage::extend_vc_status_ttl(e, &this, &vc_id);
     }
+    storage::remove_legacy_issuance_vcs(e);
+    storage::remove_legacy_issuance_revocations(e);
+}
+
+/// Move pre-scoping registry entries (keyed by vc_id only) under `issuer_addr`.
+/// `owner` is the vault currently holding the VCs; its payloads get the issuer recorded.
+/// A legacy owner record naming another vault fails with `LegacyOwnerMismatch`.
+fn migrate_registry_entries(e: &Env, issuer_addr: &Address, owner: &Address, vc_ids: &Vec<String>) {
+    validate_vault_initialized(e, owner);
+    for vc_id in vc_ids.iter() {
+        let status = match storage::read_legacy_vc_status(e, &vc_id) {
+            Some(status) => status.into_status(),
+            None => panic_with_error!(e, ContractError::VCSAlreadyMigrated),
+        };
+        if storage::read_legacy_vc_owner(e, &vc_id).is_some_and(|legacy| legacy != *owner) {
+            panic_with_error!(e, ContractError::LegacyOwnerMismatch)
+        }
+        if storage::has_vc_status(e, issuer_addr, &vc_id) {
+            panic_with_error!(e, ContractError::VCAlreadyExists)
+        }
+        storage::write_vc_status(e, issuer_addr, &vc_id, &status);
+        storage::write_vc_owner(e, issuer_addr, &vc_id, owner);
+        storage::remove_legacy_vc_status(e, &vc_id);
+        storage::remove_legacy_vc_owner(e, &vc_id);
+        if let Some(vc) = storage::read_vault_vc(e, owner, &vc_id) {
+            storage::write_vault_vc(
+                e,
+                owner,
+                &vc_id,
+                &VerifiableCredential {
+                    issuer: issuer_addr.clone(),
+                    ..vc
+                },
+            );
+        }
+        storage::extend_vc_ttl(e, owner, issuer_addr, &vc_id);
+    }
+    storage::extend_vault_ttl(e, owner);
+    events::registry_migrated(e, issuer_addr, owner, vc_ids);
 }
 
 // --- Validation helpers ---
 
-/// Ensure contract admin exists and has signed. Returns admin address.
-fn validate_contract_admin(e: &Env) -> Address {
+/// Ensure contract is initialized.
+fn validate_initialized(e: &Env) {
     if !storage::has_contract_admin(e) {
         panic_with_error!(e, ContractError::NotInitialized)
     }
-    let admin = storage::read_contract_admin(e);
+}
+
+/// Ensure one signature suffices for the contract admin and it has signed. Returns admin address.
+/// Multi-signer sets must go through `propose_admin_action`.
+fn validate_contract_admin(e: &Env) -> Address {
+    validate_initialized(e);
+    let admin = match admin::sole_admin(e) {
+        Some(admin) => admin,
+        None => panic_with_error!(e, ContractError::ProposalRequired),
+    };
     admin.require_auth();
     admin
 }
@@ -380,50 +1110,119 @@ fn validate_vault_active(e: &Env, owner: &Address) {
     }
 }
 
-/// Ensure issuer is in vault's authorized list. No signature check.
+/// Ensure issuer holds an unexpired authorization for the vault. No signature check.
 fn validate_issuer_authorized_only(e: &Env, owner: &Address, issuer_addr: &Address) {
     validate_vault_initialized(e, owner);
-    let issuers = storage::read_vault_issuers(e, owner);
-    if !vault::is_authorized(&issuers, issuer_addr) {
-        panic_with_error!(e, ContractError::IssuerNotAuthorized)
-    }
+    vault::require_authorized(e, owner, issuer_addr);
+    storage::extend_vault_issuer_ttl(e, owner, issuer_addr);
 }
 
-/// Ensure VC exists in status registry (not Invalid).
-fn validate_vc_exists(e: &Env, vc_id: &String) {
-    if storage::read_vc_status(e, vc_id) == VCStatus::Invalid {
+/// Ensure VC exists in the issuer's status registry (not Invalid).
+fn validate_vc_exists(e: &Env, issuer_addr: &Address, vc_id: &String) {
+    if storage::read_vc_status(e, issuer_addr, vc_id) == VCStatus::Invalid {
         panic_with_error!(e, ContractError::VCNotFound)
     }
 }
 
+/// Ensure revocation is not paused, VC exists, `caller` has signed, and `caller` may change its
+/// status: a sole contract admin (emergency override; a larger signer set uses
+/// `AdminAction::Revoke` / `Suspend` / `Reinstate`), or the issuer/holder as allowed by the
+/// revocation policy.
+fn validate_status_authority(e: &Env, caller: &Address, issuer_addr: &Address, vc_id: &String) {
+    admin::require_not_paused(e, PauseScope::Revocation);
+    validate_vc_exists(e, issuer_addr, vc_id);
+    caller.require_auth();
+    if admin::sole_admin(e).as_ref() == Some(caller) {
+        return;
+    }
+    let owner = storage::read_vc_owner(e, issuer_addr, vc_id);
+    let policy = issuance::revocation_policy(e, issuer_addr, owner.as_ref());
+    // The holder is whoever administers the vault now, after any handover or recovery.
+    let holder = owner
+        .as_ref()
+        .filter(|owner| storage::has_vault_admin(e, owner))
+        .map(|owner| storage::read_vault_admin(e, owner));
+    if !issuance::may_change_status(policy, caller, issuer_addr, holder.as_ref()) {
+        panic_with_error!(e, ContractError::RevocationNotAllowed)
+    }
+}
+
+/// Checks for an admin status override: revocation is not paused and the VC exists. The revocation
+/// policy does not apply; admin authority was established by the caller.
+fn validate_admin_status_change(e: &Env, issuer_addr: &Address, vc_id: &String) {
+    admin::require_not_paused(e, PauseScope::Revocation);
+    validate_vc_exists(e, issuer_addr, vc_id);
+}
+
 /// Convert VCStatus to map for verify_vc return value.
 fn issuance_status_to_map(e: &Env, status: VCStatus) -> Map<String, String> {
-    let status_k = String::from_str(e, "status");
-    let since_k = String::from_str(e, "since");
-    let revoked_v = String::from_str(e, "revoked");
-    let valid_v = String::from_str(e, "valid");
-    let invalid_v = String::from_str(e, "invalid");
+    let mut m = Map::new(e);
+    m.set(
+        String::from_str(e, "status"),
+        String::from_str(e, issuance::status_name(&status)),
+    );
     match status {
-        VCStatus::Invalid => {
-            let mut m = Map::new(e);
-            m.set(status_k, invalid_v);
-            m
+        VCStatus::Invalid | VCStatus::Valid => {}
+        VCStatus::Revoked(change, reason) => {
+            m.set(
+                String::from_str(e, "reason"),
+                revocation_reason_str(e, reason),
+            );
+            set_status_change(e, &mut m, change);
         }
-        VCStatus::Valid => {
-            let mut m = Map::new(e);
-            m.set(status_k, valid_v);
-            m
+        VCStatus::Suspended(change) => set_status_change(e, &mut m, change),
+        VCStatus::Expired(until) => m.set(String::from_str(e, "since"), u64_to_string(e, until)),
+        VCStatus::NotYetValid(from) => {
+            m.set(String::from_str(e, "valid_from"), u64_to_string(e, from))
         }
-        VCStatus::Revoked(date) => {
-            let mut m = Map::new(e);
-            m.set(status_k, revoked_v);
-            m.set(since_k, date);
-            m
+    }
+    m
+}
+
+/// Add "since" (ledger timestamp), "since_ledger" (sequence) and optional "note" to a status map.
+fn set_status_change(e: &Env, m: &mut Map<String, String>, change: StatusChange) {
+    m.set(
+        String::from_str(e, "since"),
+        u64_to_string(e, change.timestamp),
+    );
+    m.set(
+        String::from_str(e, "since_ledger"),
+        u64_to_string(e, change.ledger as u64),
+    );
+    if let Some(note) = change.note {
+        m.set(String::from_str(e, "note"), note);
+    }
+}
+
+/// Snake-case name of a revocation reason for map outputs.
+fn revocation_reason_str(e: &Env, reason: RevocationReason) -> String {
+    let s = match reason {
+        RevocationReason::Unspecified => "unspecified",
+        RevocationReason::KeyCompromise => "key_compromise",
+        RevocationReason::Superseded => "superseded",
+        RevocationReason::IssuerError => "issuer_error",
+        RevocationReason::HolderRequest => "holder_request",
+        RevocationReason::CessationOfOperation => "cessation_of_operation",
+    };
+    String::from_str(e, s)
+}
+
+/// Decimal representation of a ledger timestamp for map outputs.
+fn u64_to_string(e: &Env, mut n: u64) -> String {
+    let mut buf = [0u8; 20];
+    let mut i = buf.len();
+    loop {
+        i -= 1;
+        buf[i] = b'0' + (n % 10) as u8;
+        n /= 10;
+        if n == 0 {
+            break;
         }
     }
+    String::from_bytes(e, &buf[i..])
 }
 
-/// Store VC in vault and charge fee if enabled.
+/// Store VC in vault (or its inbox) and charge fee if enabled. Returns the inbox deadline if pending.
 fn store_vc_payload(
     e: &Env,
     owner: &Address,
@@ -432,18 +1231,21 @@ fn store_vc_payload(
     issuer_addr: &Address,
     issuer_did: String,
     issuance_contract: Address,
-    fee_override: i128,
-) {
-    if storage::read_fee_enabled(e) {
-        let fee_token = storage::read_fee_token_contract(e);
-        let fee_dest = storage::read_fee_dest(e);
-        if fee_override > 0 {
-            e.invoke_contract::<()>(
-                &fee_token,
-                &symbol_short!("transfer"),
-                (issuer_addr.clone(), fee_dest, fee_override).into_val(e),
-            );
-        }
-    }
-    vault::store_vc(e, owner, vc_id, vc_data, issuance_contract, issuer_did);
+    valid_from: Option<u64>,
+    valid_until: Option<u64>,
+    transferable: bool,
+) -> Option<u64> {
+    fee::charge_issuance_fee(e, issuer_addr);
+    vault::store_vc(
+        e,
+        owner,
+        vc_id,
+        vc_data,
+        issuance_contract,
+        issuer_addr.clone(),
+        issuer_did,
+        valid_from,
+        valid_until,
+        transferable,
+    )
 }
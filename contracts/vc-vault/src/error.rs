@@ -26,4 +26,80 @@ pub enum ContractError {
     NotInitialized = 9,
     /// vault_contract param is not this contract.
     InvalidVaultContract = 10,
+    /// VC ID already registered for this issuer or present in the vault.
+    VCAlreadyExists = 11,
+    /// Fees enabled but token contract or destination not set.
+    FeeNotConfigured = 12,
+    /// `valid_from` is not before `valid_until`.
+    InvalidValidityWindow = 13,
+    /// VC already suspended.
+    VCAlreadySuspended = 14,
+    /// VC is not suspended; nothing to reinstate.
+    VCNotSuspended = 15,
+    /// Caller may not change this VC's status under the applicable revocation policy.
+    RevocationNotAllowed = 16,
+    /// Page limit must be greater than zero.
+    InvalidPageLimit = 17,
+    /// Issuer's authorization for this vault has lapsed (or would already be lapsed).
+    IssuerAuthorizationExpired = 18,
+    /// Vault cap or the issuer's quota for this vault is used up.
+    VaultQuotaExceeded = 19,
+    /// Pending credential is past its acceptance window; it can only be rejected.
+    PendingVCExpired = 20,
+    /// VC was issued as non-transferable and cannot be pushed.
+    VCNotTransferable = 21,
+    /// Vault is not revoked; nothing to reactivate.
+    VaultNotRevoked = 22,
+    /// Reactivation cooldown since `revoke_vault` has not passed yet.
+    ReactivationCooldown = 23,
+    /// Guardian list has duplicates, includes the owner, is too long, threshold is out of range,
+    /// or the delay is below the minimum.
+    InvalidGuardianConfig = 24,
+    /// Address is not a guardian of this vault.
+    NotGuardian = 25,
+    /// No admin recovery is pending for this vault.
+    NoRecoveryPending = 26,
+    /// Recovery threshold not met or delay not yet passed.
+    RecoveryNotReady = 27,
+    /// A recovery to a different admin has already met the threshold.
+    RecoveryAlreadyPending = 28,
+    /// Address is not in the contract admin signer set.
+    NotAdminSigner = 29,
+    /// Signer set requires more than one approval; use the admin proposal flow.
+    ProposalRequired = 30,
+    /// No admin proposal with this ID (never created, or already executed).
+    ProposalNotFound = 31,
+    /// Admin proposal does not have enough approvals from current signers.
+    ProposalNotApproved = 32,
+    /// Admin proposal is past its expiry.
+    ProposalExpired = 33,
+    /// Signer set has duplicates, is empty or too long, or threshold is out of range.
+    InvalidAdminConfig = 34,
+    /// An upgrade is already announced; cancel it first.
+    UpgradeAlreadyPending = 35,
+    /// No contract upgrade is announced.
+    NoUpgradePending = 36,
+    /// Upgrade delay has not passed yet.
+    UpgradeNotReady = 37,
+    /// Upgrade delay is below the minimum.
+    InvalidUpgradeDelay = 38,
+    /// No admin handover is pending.
+    NoHandoverPending = 39,
+    /// Admin handover is past its expiry.
+    HandoverExpired = 40,
+    /// Caller did not deploy this contract with the given salt.
+    NotDeployer = 41,
+    /// This operation is paused.
+    Paused = 42,
+    /// Caller is neither the pause guardian nor the sole contract admin.
+    NotPauseGuardian = 43,
+    /// Caller does not hold the role this operation requires.
+    MissingRole = 44,
+    /// Recovery request is past its expiry.
+    RecoveryExpired = 45,
+    /// Vault still has a legacy VC index or issuer list; run `migrate_vault_index` /
+    /// `migrate_vault_issuers` first.
+    LegacyIndexNotMigrated = 46,
+    /// The legacy registry records a different owner for the VC.
+    LegacyOwnerMismatch = 47,
 }
@@ -0,0 +1,426 @@
+//! Contract events. Topics are `(area, action[, subject])`; data carries the change payload.
+
+use crate::model::{
+    AdminAction, FeeTier, PauseScope, RevocationPolicy, RevocationReason, Role, StatusChange,
+};
+use soroban_sdk::{symbol_short, Address, BytesN, Env, String, Symbol, Vec};
+
+const CONTRACT: Symbol = symbol_short!("contract");
+const FEE: Symbol = symbol_short!("fee");
+const VAULT: Symbol = symbol_short!("vault");
+const VC: Symbol = symbol_short!("vc");
+
+// --- Global config ---
+
+/// `("contract", "init")` -> `(contract_admin, default_issuer_did)`.
+pub fn initialized(e: &Env, contract_admin: &Address, default_issuer_did: &String) {
+    e.events().publish(
+        (CONTRACT, symbol_short!("init")),
+        (contract_admin.clone(), default_issuer_did.clone()),
+    );
+}
+
+/// `("contract", "admin")` -> `new_admin`.
+pub fn contract_admin_set(e: &Env, new_admin: &Address) {
+    e.events()
+        .publish((CONTRACT, symbol_short!("admin")), new_admin.clone());
+}
+
+/// `("contract", "adm_prop")` -> `(new_admin, expires_at)`.
+pub fn contract_admin_proposed(e: &Env, new_admin: &Address, expires_at: u64) {
+    e.events().publish(
+        (CONTRACT, symbol_short!("adm_prop")),
+        (new_admin.clone(), expires_at),
+    );
+}
+
+/// `("contract", "signers")` -> `(signers, threshold)`.
+pub fn admin_signers_set(e: &Env, signers: &Vec<Address>, threshold: u32) {
+    e.events().publish(
+        (CONTRACT, symbol_short!("signers")),
+        (signers.clone(), threshold),
+    );
+}
+
+/// `("contract", "proposed", id)` -> `(proposer, action, expires_at)`.
+pub fn admin_action_proposed(
+    e: &Env,
+    id: u32,
+    proposer: &Address,
+    action: &AdminAction,
+    expires_at: u64,
+) {
+    e.events().publish(
+        (CONTRACT, symbol_short!("proposed"), id),
+        (proposer.clone(), action.clone(), expires_at),
+    );
+}
+
+/// `("contract", "approved", id)` -> `signer`.
+pub fn admin_action_approved(e: &Env, id: u32, signer: &Address) {
+    e.events()
+        .publish((CONTRACT, symbol_short!("approved"), id), signer.clone());
+}
+
+/// `("contract", "executed", id)` -> `action`.
+pub fn admin_action_executed(e: &Env, id: u32, action: &AdminAction) {
+    e.events()
+        .publish((CONTRACT, symbol_short!("executed"), id), action.clone());
+}
+
+/// `("contract", "upg_prop")` -> `(wasm_hash, executable_at)`.
+pub fn upgrade_proposed(e: &Env, wasm_hash: &BytesN<32>, executable_at: u64) {
+    e.events().publish(
+        (CONTRACT, symbol_short!("upg_prop")),
+        (wasm_hash.clone(), executable_at),
+    );
+}
+
+/// `("contract", "upg_cncl")` -> `wasm_hash`.
+pub fn upgrade_cancelled(e: &Env, wasm_hash: &BytesN<32>) {
+    e.events()
+        .publish((CONTRACT, symbol_short!("upg_cncl")), wasm_hash.clone());
+}
+
+/// `("contract", "upg_delay")` -> `seconds`.
+pub fn upgrade_delay_set(e: &Env, seconds: u64) {
+    e.events()
+        .publish((CONTRACT, symbol_short!("upg_delay")), seconds);
+}
+
+/// `("contract", "upgrade")` -> `new_wasm_hash`.
+pub fn upgraded(e: &Env, new_wasm_hash: &BytesN<32>) {
+    e.events()
+        .publish((CONTRACT, symbol_short!("upgrade")), new_wasm_hash.clone());
+}
+
+/// `("contract", "paused")` -> `scopes`.
+pub fn paused(e: &Env, scopes: &Vec<PauseScope>) {
+    e.events()
+        .publish((CONTRACT, symbol_short!("paused")), scopes.clone());
+}
+
+/// `("contract", "unpaused")` -> `scopes`.
+pub fn unpaused(e: &Env, scopes: &Vec<PauseScope>) {
+    e.events()
+        .publish((CONTRACT, symbol_short!("unpaused")), scopes.clone());
+}
+
+/// `("contract", "pause_grd")` -> `guardian` (None = removed).
+pub fn pause_guardian_set(e: &Env, guardian: &Option<Address>) {
+    e.events()
+        .publish((CONTRACT, symbol_short!("pause_grd")), guardian.clone());
+}
+
+/// `("contract", "role_gnt")` -> `(role, account)`.
+pub fn role_granted(e: &Env, role: Role, account: &Address) {
+    e.events().publish(
+        (CONTRACT, symbol_short!("role_gnt")),
+        (role, account.clone()),
+    );
+}
+
+/// `("contract", "role_rvk")` -> `(role, account)`. Also published on renounce.
+pub fn role_revoked(e: &Env, role: Role, account: &Address) {
+    e.events().publish(
+        (CONTRACT, symbol_short!("role_rvk")),
+        (role, account.clone()),
+    );
+}
+
+/// `("contract", "migrate")` -> `owner` (None = issuance registry migration).
+pub fn migrated(e: &Env, owner: &Option<Address>) {
+    e.events()
+        .publish((CONTRACT, symbol_short!("migrate")), owner.clone());
+}
+
+/// `("contract", "mig_reg", issuer)` -> `(owner, vc_ids)`.
+pub fn registry_migrated(e: &Env, issuer: &Address, owner: &Address, vc_ids: &Vec<String>) {
+    e.events().publish(
+        (CONTRACT, symbol_short!("mig_reg"), issuer.clone()),
+        (owner.clone(), vc_ids.clone()),
+    );
+}
+
+/// `("contract", "mig_index", owner)` -> number of IDs moved into the paged index.
+pub fn vault_index_migrated(e: &Env, owner: &Address, count: u32) {
+    e.events()
+        .publish((CONTRACT, symbol_short!("mig_index"), owner.clone()), count);
+}
+
+/// `("contract", "mig_iss", owner)` -> number of issuers moved into per-issuer entries.
+pub fn vault_issuers_migrated(e: &Env, owner: &Address, count: u32) {
+    e.events()
+        .publish((CONTRACT, symbol_short!("mig_iss"), owner.clone()), count);
+}
+
+/// `("fee", "config")` -> `(token_contract, fee_dest)`.
+pub fn fee_config_set(e: &Env, token_contract: &Address, fee_dest: &Address) {
+    e.events().publish(
+        (FEE, symbol_short!("config")),
+        (token_contract.clone(), fee_dest.clone()),
+    );
+}
+
+/// `("fee", "enabled")` -> `enabled`.
+pub fn fee_enabled_set(e: &Env, enabled: bool) {
+    e.events().publish((FEE, symbol_short!("enabled")), enabled);
+}
+
+/// `("fee", tier)` -> `fee_amount`. Tier is `admin`, `standard` or `early`.
+pub fn fee_tier_set(e: &Env, tier: Symbol, fee_amount: i128) {
+    e.events().publish((FEE, tier), fee_amount);
+}
+
+/// `("fee", "custom", issuer)` -> `fee_amount`.
+pub fn fee_custom_set(e: &Env, issuer: &Address, fee_amount: i128) {
+    e.events()
+        .publish((FEE, symbol_short!("custom"), issuer.clone()), fee_amount);
+}
+
+/// `("fee", "tier", issuer)` -> `tier`.
+pub fn issuer_tier_set(e: &Env, issuer: &Address, tier: FeeTier) {
+    e.events()
+        .publish((FEE, symbol_short!("tier"), issuer.clone()), tier);
+}
+
+// --- Vault ---
+
+/// `("vault", "created", owner)` -> `did_uri`.
+pub fn vault_created(e: &Env, owner: &Address, did_uri: &String) {
+    e.events().publish(
+        (VAULT, symbol_short!("created"), owner.clone()),
+        did_uri.clone(),
+    );
+}
+
+/// `("vault", "admin", owner)` -> `new_admin`.
+pub fn vault_admin_set(e: &Env, owner: &Address, new_admin: &Address) {
+    e.events().publish(
+        (VAULT, symbol_short!("admin"), owner.clone()),
+        new_admin.clone(),
+    );
+}
+
+/// `("vault", "adm_prop", owner)` -> `(new_admin, expires_at)`.
+pub fn vault_admin_proposed(e: &Env, owner: &Address, new_admin: &Address, expires_at: u64) {
+    e.events().publish(
+        (VAULT, symbol_short!("adm_prop"), owner.clone()),
+        (new_admin.clone(), expires_at),
+    );
+}
+
+/// `("vault", "issuers", owner)` -> issuers added in one batch.
+pub fn issuers_added(e: &Env, owner: &Address, issuers: &Vec<Address>) {
+    e.events().publish(
+        (VAULT, symbol_short!("issuers"), owner.clone()),
+        issuers.clone(),
+    );
+}
+
+/// `("vault", "iss_auth", owner)` -> `issuer`.
+pub fn issuer_authorized(e: &Env, owner: &Address, issuer: &Address) {
+    e.events().publish(
+        (VAULT, symbol_short!("iss_auth"), owner.clone()),
+        issuer.clone(),
+    );
+}
+
+/// `("vault", "iss_rev", owner)` -> `issuer`.
+pub fn issuer_revoked(e: &Env, owner: &Address, issuer: &Address) {
+    e.events().publish(
+        (VAULT, symbol_short!("iss_rev"), owner.clone()),
+        issuer.clone(),
+    );
+}
+
+/// `("vault", "quota", owner)` -> `(issuer, quota)`. `quota` None = unlimited.
+pub fn issuer_quota_set(e: &Env, owner: &Address, issuer: &Address, quota: Option<u32>) {
+    e.events().publish(
+        (VAULT, symbol_short!("quota"), owner.clone()),
+        (issuer.clone(), quota),
+    );
+}
+
+/// `("vault", "cap", owner)` -> `cap`. None = unlimited.
+pub fn vault_cap_set(e: &Env, owner: &Address, cap: Option<u32>) {
+    e.events()
+        .publish((VAULT, symbol_short!("cap"), owner.clone()), cap);
+}
+
+/// `("vault", "inbox", owner)` -> `ttl`. None = inbox off.
+pub fn inbox_set(e: &Env, owner: &Address, ttl: Option<u64>) {
+    e.events()
+        .publish((VAULT, symbol_short!("inbox"), owner.clone()), ttl);
+}
+
+/// `("vault", "xfer_from", owner)` -> `(sender, allowed)`.
+pub fn transfer_allowed_set(e: &Env, owner: &Address, sender: &Address, allowed: bool) {
+    e.events().publish(
+        (VAULT, symbol_short!("xfer_from"), owner.clone()),
+        (sender.clone(), allowed),
+    );
+}
+
+/// `("vault", "revoked", owner)` -> `()`.
+pub fn vault_revoked(e: &Env, owner: &Address) {
+    e.events()
+        .publish((VAULT, symbol_short!("revoked"), owner.clone()), ());
+}
+
+/// `("vault", "guardians", owner)` -> `(guardians, threshold, delay)`.
+pub fn guardians_set(
+    e: &Env,
+    owner: &Address,
+    guardians: &Vec<Address>,
+    threshold: u32,
+    delay: u64,
+) {
+    e.events().publish(
+        (VAULT, symbol_short!("guardians"), owner.clone()),
+        (guardians.clone(), threshold, delay),
+    );
+}
+
+/// `("vault", "rec_appr", owner)` -> `(guardian, new_admin, executable_at)`.
+pub fn recovery_approved(
+    e: &Env,
+    owner: &Address,
+    guardian: &Address,
+    new_admin: &Address,
+    executable_at: Option<u64>,
+) {
+    e.events().publish(
+        (VAULT, symbol_short!("rec_appr"), owner.clone()),
+        (guardian.clone(), new_admin.clone(), executable_at),
+    );
+}
+
+/// `("vault", "recovered", owner)` -> `new_admin`.
+pub fn recovery_executed(e: &Env, owner: &Address, new_admin: &Address) {
+    e.events().publish(
+        (VAULT, symbol_short!("recovered"), owner.clone()),
+        new_admin.clone(),
+    );
+}
+
+/// `("vault", "rec_cncl", owner)` -> `()`.
+pub fn recovery_cancelled(e: &Env, owner: &Address) {
+    e.events()
+        .publish((VAULT, symbol_short!("rec_cncl"), owner.clone()), ());
+}
+
+/// `("vault", "restored", owner)` -> `()`.
+pub fn vault_reactivated(e: &Env, owner: &Address) {
+    e.events()
+        .publish((VAULT, symbol_short!("restored"), owner.clone()), ());
+}
+
+/// `("vault", "closing", owner)` -> entries left; `("vault", "closed", owner)` once 0.
+pub fn vault_close_progress(e: &Env, owner: &Address, remaining: u32) {
+    let topic = if remaining == 0 {
+        symbol_short!("closed")
+    } else {
+        symbol_short!("closing")
+    };
+    e.events().publish((VAULT, topic, owner.clone()), remaining);
+}
+
+/// `("contract", "cooldown")` -> `seconds`.
+pub fn reactivation_cooldown_set(e: &Env, seconds: u64) {
+    e.events()
+        .publish((CONTRACT, symbol_short!("cooldown")), seconds);
+}
+
+// --- Credentials ---
+
+/// `("vc", "issued", owner)` -> `(vc_id, issuer)`.
+pub fn vc_issued(e: &Env, owner: &Address, vc_id: &String, issuer: &Address) {
+    e.events().publish(
+        (VC, symbol_short!("issued"), owner.clone()),
+        (vc_id.clone(), issuer.clone()),
+    );
+}
+
+/// `("vc", "revoked", issuer)` -> `(vc_id, change, reason)`.
+pub fn vc_revoked(
+    e: &Env,
+    issuer: &Address,
+    vc_id: &String,
+    change: &StatusChange,
+    reason: RevocationReason,
+) {
+    e.events().publish(
+        (VC, symbol_short!("revoked"), issuer.clone()),
+        (vc_id.clone(), change.clone(), reason),
+    );
+}
+
+/// `("vc", "suspended", issuer)` -> `(vc_id, change)`.
+pub fn vc_suspended(e: &Env, issuer: &Address, vc_id: &String, change: &StatusChange) {
+    e.events().publish(
+        (VC, symbol_short!("suspended"), issuer.clone()),
+        (vc_id.clone(), change.clone()),
+    );
+}
+
+/// `("vc", "reinstate", issuer)` -> `vc_id`.
+pub fn vc_reinstated(e: &Env, issuer: &Address, vc_id: &String) {
+    e.events().publish(
+        (VC, symbol_short!("reinstate"), issuer.clone()),
+        vc_id.clone(),
+    );
+}
+
+/// `("vc", "policy", issuer)` -> `(owner, policy)`. `owner` None = issuer-wide default.
+pub fn revocation_policy_set(
+    e: &Env,
+    issuer: &Address,
+    owner: &Option<Address>,
+    policy: RevocationPolicy,
+) {
+    e.events().publish(
+        (VC, symbol_short!("policy"), issuer.clone()),
+        (owner.clone(), policy),
+    );
+}
+
+/// `("vc", "pending", owner)` -> `(vc_id, expires_at)`.
+pub fn vc_pending(e: &Env, owner: &Address, vc_id: &String, expires_at: u64) {
+    e.events().publish(
+        (VC, symbol_short!("pending"), owner.clone()),
+        (vc_id.clone(), expires_at),
+    );
+}
+
+/// `("vc", "accepted", owner)` -> `vc_id`.
+pub fn vc_accepted(e: &Env, owner: &Address, vc_id: &String) {
+    e.events().publish(
+        (VC, symbol_short!("accepted"), owner.clone()),
+        vc_id.clone(),
+    );
+}
+
+/// `("vc", "rejected", owner)` -> `(vc_id, sender)`. `sender` is set when returned to it.
+pub fn vc_rejected(e: &Env, owner: &Address, vc_id: &String, sender: Option<Address>) {
+    e.events().publish(
+        (VC, symbol_short!("rejected"), owner.clone()),
+        (vc_id.clone(), sender),
+    );
+}
+
+/// `("vc", "deleted", owner)` -> `(vc_id, tombstone)`.
+pub fn vc_deleted(e: &Env, owner: &Address, vc_id: &String, tombstone: bool) {
+    e.events().publish(
+        (VC, symbol_short!("deleted"), owner.clone()),
+        (vc_id.clone(), tombstone),
+    );
+}
+
+/// `("vc", "pushed", from_owner)` -> `(to_owner, vc_id)`.
+pub fn vc_pushed(e: &Env, from_owner: &Address, to_owner: &Address, vc_id: &String) {
+    e.events().publish(
+        (VC, symbol_short!("pushed"), from_owner.clone()),
+        (to_owner.clone(), vc_id.clone()),
+    );
+}
@@ -0,0 +1,45 @@
+//! Issuance fee: resolve the issuer's tier and charge it in the configured token.
+
+use crate::admin;
+use crate::error::ContractError;
+use crate::model::FeeTier;
+use crate::storage;
+use soroban_sdk::{panic_with_error, symbol_short, Address, Env, IntoVal};
+
+/// Fee owed by `issuer` per issuance.
+/// Contract admin signer -> admin tier; otherwise custom override -> early tier -> standard tier.
+pub fn issuance_fee(e: &Env, issuer: &Address) -> i128 {
+    if admin::is_signer(e, issuer) {
+        return storage::read_fee_admin(e);
+    }
+    if let Some(custom) = storage::try_read_fee_custom(e, issuer) {
+        return custom;
+    }
+    match storage::read_issuer_tier(e, issuer) {
+        FeeTier::Early => storage::read_fee_early(e),
+        FeeTier::Standard => storage::read_fee_standard(e),
+    }
+}
+
+/// Transfer the issuance fee from `issuer` to the fee destination. No-op when fees are disabled.
+pub fn charge_issuance_fee(e: &Env, issuer: &Address) {
+    if !storage::read_fee_enabled(e) {
+        return;
+    }
+    let amount = issuance_fee(e, issuer);
+    if amount <= 0 {
+        return;
+    }
+    let (fee_token, fee_dest) = match (
+        storage::try_read_fee_token_contract(e),
+        storage::try_read_fee_dest(e),
+    ) {
+        (Some(token), Some(dest)) => (token, dest),
+        _ => panic_with_error!(e, ContractError::FeeNotConfigured),
+    };
+    e.invoke_contract::<()>(
+        &fee_token,
+        &symbol_short!("transfer"),
+        (issuer.clone(), fee_dest, amount).into_val(e),
+    );
+}
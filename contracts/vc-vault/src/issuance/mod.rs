@@ -1,15 +1,129 @@
-//! Issuance status registry: revoke VC by ID.
+//! Issuance status registry: revoke/suspend/reinstate VC by (issuer, ID), apply validity windows
+//! and revocation policies.
 
 use crate::error::ContractError;
-use crate::model::VCStatus;
+use crate::model::{
+    RevocationPolicy, RevocationReason, StatusChange, VCStatus, VerifiableCredential,
+};
 use crate::storage;
-use soroban_sdk::{panic_with_error, Env, String};
+use soroban_sdk::{panic_with_error, Address, Env, String};
 
-/// Set VC status to Revoked. Panics if not Valid.
-pub fn revoke_vc(e: &Env, vc_id: String, date: String) {
-    let vc_status = storage::read_vc_status(e, &vc_id);
-    if vc_status != VCStatus::Valid {
-        panic_with_error!(e, ContractError::VCAlreadyRevoked)
+/// Policy for VCs `issuer` wrote to `owner`'s vault: per-vault override, else issuer default,
+/// else IssuerOrHolder.
+pub fn revocation_policy(e: &Env, issuer: &Address, owner: Option<&Address>) -> RevocationPolicy {
+    owner
+        .and_then(|owner| storage::read_vault_revocation_policy(e, issuer, owner))
+        .or_else(|| storage::read_revocation_policy(e, issuer))
+        .unwrap_or(RevocationPolicy::IssuerOrHolder)
+}
+
+/// Whether `caller` may change status under `policy`, as issuer or as holder (the current
+/// admin of the vault holding the VC).
+pub fn may_change_status(
+    policy: RevocationPolicy,
+    caller: &Address,
+    issuer: &Address,
+    holder: Option<&Address>,
+) -> bool {
+    let is_issuer = caller == issuer;
+    let is_holder = holder == Some(caller);
+    match policy {
+        RevocationPolicy::IssuerOnly => is_issuer,
+        RevocationPolicy::HolderOnly => is_holder,
+        RevocationPolicy::IssuerOrHolder => is_issuer || is_holder,
+    }
+}
+
+/// Status name as reported by `verify_vc` under "status".
+pub fn status_name(status: &VCStatus) -> &'static str {
+    match status {
+        VCStatus::Valid => "valid",
+        VCStatus::Invalid => "invalid",
+        VCStatus::Revoked(..) => "revoked",
+        VCStatus::Suspended(_) => "suspended",
+        VCStatus::Expired(_) => "expired",
+        VCStatus::NotYetValid(_) => "not_yet_valid",
+    }
+}
+
+/// Status change stamped with the current ledger time and sequence.
+pub fn status_change(e: &Env, note: Option<String>) -> StatusChange {
+    StatusChange {
+        timestamp: e.ledger().timestamp(),
+        ledger: e.ledger().sequence(),
+        note,
+    }
+}
+
+/// Set VC status to Revoked with `reason`. Panics unless Valid or Suspended.
+pub fn revoke_vc(
+    e: &Env,
+    issuer: &Address,
+    vc_id: String,
+    reason: RevocationReason,
+    note: Option<String>,
+) -> StatusChange {
+    match storage::read_vc_status(e, issuer, &vc_id) {
+        VCStatus::Valid | VCStatus::Suspended(_) => {}
+        _ => panic_with_error!(e, ContractError::VCAlreadyRevoked),
+    }
+    let change = status_change(e, note);
+    storage::write_vc_status(
+        e,
+        issuer,
+        &vc_id,
+        &VCStatus::Revoked(change.clone(), reason),
+    );
+    change
+}
+
+/// Set VC status to Suspended. Panics unless Valid.
+pub fn suspend_vc(e: &Env, issuer: &Address, vc_id: String, note: Option<String>) -> StatusChange {
+    match storage::read_vc_status(e, issuer, &vc_id) {
+        VCStatus::Valid => {}
+        VCStatus::Suspended(_) => panic_with_error!(e, ContractError::VCAlreadySuspended),
+        _ => panic_with_error!(e, ContractError::VCAlreadyRevoked),
+    }
+    let change = status_change(e, note);
+    storage::write_vc_status(e, issuer, &vc_id, &VCStatus::Suspended(change.clone()));
+    change
+}
+
+/// Set Suspended VC back to Valid. Revoked VCs can never be reinstated.
+pub fn reinstate_vc(e: &Env, issuer: &Address, vc_id: String) {
+    match storage::read_vc_status(e, issuer, &vc_id) {
+        VCStatus::Suspended(_) => {}
+        VCStatus::Revoked(..) => panic_with_error!(e, ContractError::VCAlreadyRevoked),
+        _ => panic_with_error!(e, ContractError::VCNotSuspended),
+    }
+    storage::write_vc_status(e, issuer, &vc_id, &VCStatus::Valid)
+}
+
+/// Panic unless `valid_from` is strictly before `valid_until` (when both are set).
+pub fn validate_window(e: &Env, valid_from: Option<u64>, valid_until: Option<u64>) {
+    if let (Some(from), Some(until)) = (valid_from, valid_until) {
+        if from >= until {
+            panic_with_error!(e, ContractError::InvalidValidityWindow)
+        }
+    }
+}
+
+/// Apply the VC's validity window to a stored registry status at the current ledger time.
+/// Only `Valid` is affected; revocation always takes precedence.
+pub fn effective_status(e: &Env, vc: &VerifiableCredential, status: VCStatus) -> VCStatus {
+    if status != VCStatus::Valid {
+        return status;
+    }
+    let now = e.ledger().timestamp();
+    if let Some(from) = vc.valid_from {
+        if now < from {
+            return VCStatus::NotYetValid(from);
+        }
+    }
+    if let Some(until) = vc.valid_until {
+        if now > until {
+            return VCStatus::Expired(until);
+        }
     }
-    storage::write_vc_status(e, &vc_id, &VCStatus::Revoked(date))
+    status
 }
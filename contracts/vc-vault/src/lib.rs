@@ -5,10 +5,14 @@
 
 #![no_std]
 #![allow(dead_code)]
+#![allow(clippy::too_many_arguments)]
 
+mod admin;
 mod api;
 mod contract;
 mod error;
+mod events;
+mod fee;
 mod issuance;
 mod model;
 mod storage;
@@ -0,0 +1,14 @@
+//! Admin handover awaiting acceptance by the incoming admin.
+
+use soroban_sdk::{contracttype, Address};
+
+/// Proposed admin change (contract or vault). Takes effect only once `new_admin` accepts.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminHandover {
+    /// Incoming admin; must sign the acceptance.
+    pub new_admin: Address,
+
+    /// Ledger timestamp after which the handover can no longer be accepted.
+    pub expires_at: u64,
+}
@@ -0,0 +1,62 @@
+//! Privileged operations gated by the contract admin signer set, and proposals to run them.
+
+use crate::model::{FeeTier, PauseScope, RevocationReason, Role};
+use soroban_sdk::{contracttype, Address, BytesN, String, Vec};
+
+/// A contract-admin operation. Each variant mirrors the direct entrypoint of the same name.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AdminAction {
+    /// `(token_contract, fee_dest)`.
+    SetFeeConfig(Address, Address),
+    SetFeeEnabled(bool),
+    SetFeeAdmin(i128),
+    SetFeeStandard(i128),
+    SetFeeEarly(i128),
+    /// `(issuer, fee_amount)`.
+    SetFeeCustom(Address, i128),
+    SetIssuerTier(Address, FeeTier),
+    SetReactivationCooldown(u64),
+    ProposeUpgrade(BytesN<32>),
+    CancelUpgrade,
+    SetUpgradeDelay(u64),
+    /// Hand the admin role to one address; takes effect once it calls `accept_contract_admin`.
+    ProposeContractAdmin(Address),
+    Pause(Vec<PauseScope>),
+    Unpause(Vec<PauseScope>),
+    /// Address that may `pause` (but not unpause) on its own. None removes it.
+    SetPauseGuardian(Option<Address>),
+    /// `(role, account)`.
+    GrantRole(Role, Address),
+    /// `(role, account)`.
+    RevokeRole(Role, Address),
+    /// Emergency status override: `(issuer, vc_id, reason)`, regardless of revocation policy.
+    Revoke(Address, String, RevocationReason),
+    /// `(issuer, vc_id)`.
+    Suspend(Address, String),
+    /// `(issuer, vc_id)`.
+    Reinstate(Address, String),
+    /// `migrate(None)`: legacy issuance registry.
+    MigrateIssuance,
+    /// `(issuer, owner, vc_ids)`.
+    MigrateRegistry(Address, Address, Vec<String>),
+    AddSigner(Address),
+    RemoveSigner(Address),
+    SetThreshold(u32),
+}
+
+/// Admin action awaiting approval by the signer set.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminProposal {
+    pub action: AdminAction,
+
+    /// Signer that created the proposal.
+    pub proposer: Address,
+
+    /// Signers that approved so far (the proposer included). Only current signers count.
+    pub approvals: Vec<Address>,
+
+    /// Ledger timestamp after which the proposal can no longer be approved or executed.
+    pub expires_at: u64,
+}
@@ -0,0 +1,14 @@
+//! Fee tier assigned to an issuer.
+
+use soroban_sdk::contracttype;
+
+/// Tier used to price issuance when no custom fee is set for the issuer.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FeeTier {
+    /// Default tier; charged `fee_standard`.
+    Standard,
+
+    /// Early-adopter tier; charged `fee_early`.
+    Early,
+}
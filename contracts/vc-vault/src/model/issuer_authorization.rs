@@ -0,0 +1,35 @@
+//! Per-vault issuer authorization record and its listing page.
+
+use soroban_sdk::{contracttype, Address, String, Vec};
+
+/// One issuer's write access to a vault.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IssuerAuthorization {
+    /// Authorized issuer address.
+    pub issuer: Address,
+
+    /// Ledger timestamp when access was granted (migration time for pre-existing issuers).
+    pub authorized_at: u64,
+
+    /// Ledger timestamp after which access lapses; None = open-ended.
+    pub expires_at: Option<u64>,
+
+    /// Holder-facing note on why the issuer was authorized.
+    pub label: Option<String>,
+
+    /// Credential types the holder expects from this issuer; empty = any. Informational only:
+    /// `issue` takes no credential type and does not check it.
+    pub credential_types: Vec<String>,
+}
+
+/// One page of `list_issuers` results.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IssuerPage {
+    /// Authorizations in index order.
+    pub issuers: Vec<IssuerAuthorization>,
+
+    /// Cursor for the next call; None when the index is exhausted.
+    pub next_cursor: Option<u32>,
+}
@@ -1,7 +1,35 @@
 //! Shared data types. No storage dependencies; used by storage, vault, issuance.
 
+mod admin_handover;
+mod admin_proposal;
+mod fee_tier;
+mod issuer_authorization;
+mod pause_scope;
+mod pending_upgrade;
+mod pending_vc;
+mod recovery;
+mod revocation_policy;
+mod revocation_reason;
+mod role;
+mod status_change;
+mod vc_page;
 mod vc_status;
+mod vc_tombstone;
 mod verifiable_credential;
 
+pub use admin_handover::AdminHandover;
+pub use admin_proposal::{AdminAction, AdminProposal};
+pub use fee_tier::FeeTier;
+pub use issuer_authorization::{IssuerAuthorization, IssuerPage};
+pub use pause_scope::PauseScope;
+pub use pending_upgrade::PendingUpgrade;
+pub use pending_vc::{PendingPage, PendingVC};
+pub use recovery::{GuardianConfig, RecoveryRequest};
+pub use revocation_policy::RevocationPolicy;
+pub use revocation_reason::RevocationReason;
+pub use role::Role;
+pub use status_change::StatusChange;
+pub use vc_page::{VCFilter, VCPage};
 pub use vc_status::VCStatus;
+pub use vc_tombstone::VCTombstone;
 pub use verifiable_credential::VerifiableCredential;
@@ -0,0 +1,20 @@
+//! Contract areas that can be paused independently.
+
+use soroban_sdk::contracttype;
+
+/// Group of state-changing entrypoints stopped by `pause`. Reads are never paused.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PauseScope {
+    /// `issue`.
+    Issuance,
+
+    /// `push`.
+    Transfers,
+
+    /// `revoke`, `suspend` and `reinstate`.
+    Revocation,
+
+    /// `create_vault`.
+    VaultCreation,
+}
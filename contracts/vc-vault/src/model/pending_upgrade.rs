@@ -0,0 +1,17 @@
+//! Announced contract upgrade waiting out its timelock.
+
+use soroban_sdk::{contracttype, BytesN};
+
+/// WASM swap scheduled by the contract admin. Anyone can execute it once the delay has passed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingUpgrade {
+    /// Hash of the uploaded WASM to install.
+    pub wasm_hash: BytesN<32>,
+
+    /// Ledger timestamp of the announcement.
+    pub proposed_at: u64,
+
+    /// Ledger timestamp from which it can be executed.
+    pub executable_at: u64,
+}
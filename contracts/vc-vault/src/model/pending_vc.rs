@@ -0,0 +1,32 @@
+//! Credentials waiting in a vault's inbox for the holder's decision.
+
+use super::VerifiableCredential;
+use soroban_sdk::{contracttype, Address, Vec};
+
+/// Incoming credential held outside the vault index until accepted or rejected.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingVC {
+    /// Credential as it will be stored on acceptance.
+    pub vc: VerifiableCredential,
+
+    /// Vault owner that pushed it; None when delivered by `issue`.
+    pub sender: Option<Address>,
+
+    /// Ledger timestamp when it arrived.
+    pub received_at: u64,
+
+    /// Ledger timestamp after which it can no longer be accepted.
+    pub expires_at: u64,
+}
+
+/// One page of `list_pending` results.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingPage {
+    /// Pending credentials in index order, including expired ones awaiting cleanup.
+    pub items: Vec<PendingVC>,
+
+    /// Cursor for the next call; None when the index is exhausted.
+    pub next_cursor: Option<u32>,
+}
@@ -0,0 +1,38 @@
+//! Guardian set and pending admin recovery requests for a vault.
+
+use soroban_sdk::{contracttype, Address, Vec};
+
+/// Addresses that can jointly rotate the vault admin after a delay.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GuardianConfig {
+    /// Guardian addresses (no duplicates).
+    pub guardians: Vec<Address>,
+
+    /// Approvals needed to start the delay.
+    pub threshold: u32,
+
+    /// Seconds between reaching the threshold and the rotation becoming executable (at least 1 day).
+    pub delay: u64,
+}
+
+/// Admin rotation proposed by guardians. The current admin can cancel it until executed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecoveryRequest {
+    /// Proposed vault admin.
+    pub new_admin: Address,
+
+    /// Guardians that approved so far.
+    pub approvals: Vec<Address>,
+
+    /// Ledger timestamp of the first approval.
+    pub initiated_at: u64,
+
+    /// Ledger timestamp from which it can be executed; None until the threshold is met.
+    pub executable_at: Option<u64>,
+
+    /// Ledger timestamp after which the request lapses: 7 days after the first approval, or
+    /// 7 days after `executable_at` once the threshold is met.
+    pub expires_at: u64,
+}
@@ -0,0 +1,17 @@
+//! Who may change a VC's registry status (revoke, suspend, reinstate).
+
+use soroban_sdk::contracttype;
+
+/// Parties allowed to change status. The contract admin may always act as an emergency override.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RevocationPolicy {
+    /// Only the issuer that signed the issuance.
+    IssuerOnly,
+
+    /// Only the holder (registered owner).
+    HolderOnly,
+
+    /// Issuer or holder. Default.
+    IssuerOrHolder,
+}
@@ -0,0 +1,26 @@
+//! Why a VC was revoked (modelled on CRL reason codes).
+
+use soroban_sdk::contracttype;
+
+/// Reason recorded with a revocation. Lets relying parties judge earlier presentations.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RevocationReason {
+    /// No reason given. Also assigned to revocations migrated from legacy storage.
+    Unspecified,
+
+    /// Subject or issuer key was compromised; earlier presentations are suspect.
+    KeyCompromise,
+
+    /// Replaced by a newer credential.
+    Superseded,
+
+    /// Issued in error (wrong data or subject).
+    IssuerError,
+
+    /// Holder asked for the revocation.
+    HolderRequest,
+
+    /// Issuer or program stopped operating.
+    CessationOfOperation,
+}
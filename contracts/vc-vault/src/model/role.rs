@@ -0,0 +1,23 @@
+//! Delegated contract admin duties.
+
+use soroban_sdk::contracttype;
+
+/// Role that may run a subset of admin actions through `execute_role_action`.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Role {
+    /// Global fee config and tier amounts.
+    FeeManager,
+
+    /// Announce and cancel upgrades. The upgrade delay stays with the admin.
+    Upgrader,
+
+    /// Contract-level legacy migrations.
+    Migrator,
+
+    /// Pause and unpause.
+    Pauser,
+
+    /// Per-issuer fee tiers and custom fees.
+    IssuerCurator,
+}
@@ -0,0 +1,17 @@
+//! Ledger-anchored record of a registry status change.
+
+use soroban_sdk::{contracttype, String};
+
+/// When a status change was recorded on-chain, plus an optional caller note.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StatusChange {
+    /// Ledger timestamp (seconds) of the change. Authoritative time for point-in-time checks.
+    pub timestamp: u64,
+
+    /// Ledger sequence number of the change.
+    pub ledger: u32,
+
+    /// Human-readable note or reason supplied by the caller. Not verified.
+    pub note: Option<String>,
+}
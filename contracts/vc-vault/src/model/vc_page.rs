@@ -0,0 +1,31 @@
+//! Paginated credential listing types.
+
+use super::VerifiableCredential;
+use soroban_sdk::{contracttype, Address, String, Vec};
+
+/// Optional filters for `list_vcs`. Unset fields match everything.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VCFilter {
+    /// Match VCs with this issuer DID.
+    pub issuer_did: Option<String>,
+
+    /// Match VCs whose status lives in this issuance contract.
+    pub issuance_contract: Option<Address>,
+
+    /// Match VCs whose `verify_vc` status has this name (e.g. "valid", "revoked").
+    /// Only VCs registered in this contract can match.
+    pub status: Option<String>,
+}
+
+/// One page of `list_vcs` results.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VCPage {
+    /// Matching VCs in index order.
+    pub vcs: Vec<VerifiableCredential>,
+
+    /// Cursor for the next call; None when the index is exhausted. Only valid while the vault
+    /// is unchanged; restart from 0 after a removal to be sure of seeing every VC.
+    pub next_cursor: Option<u32>,
+}
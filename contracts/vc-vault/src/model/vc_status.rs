@@ -1,6 +1,7 @@
 //! VC status in the issuance registry.
 
-use soroban_sdk::{contracttype, String};
+use super::{RevocationReason, StatusChange};
+use soroban_sdk::contracttype;
 
 /// Status of a VC in the issuance registry.
 #[derive(PartialEq)]
@@ -12,6 +13,15 @@ pub enum VCStatus {
     /// VC does not exist in the registry.
     Invalid,
 
-    /// VC was revoked at the given ISO-8601 date string.
-    Revoked(String),
+    /// VC was revoked for the given reason. Terminal.
+    Revoked(StatusChange, RevocationReason),
+
+    /// VC is temporarily on hold. Can be reinstated.
+    Suspended(StatusChange),
+
+    /// VC validity window ended at the given ledger timestamp. Derived at verification; never stored.
+    Expired(u64),
+
+    /// VC validity window starts at the given ledger timestamp. Derived at verification; never stored.
+    NotYetValid(u64),
 }
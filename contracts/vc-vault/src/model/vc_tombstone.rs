@@ -0,0 +1,20 @@
+//! Marker left in a vault when the holder deletes a credential with `tombstone = true`.
+
+use soroban_sdk::{contracttype, Address, String};
+
+/// What remains of a deleted VC. Holds no payload; blocks the ID from re-entering the vault.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VCTombstone {
+    /// Deleted VC identifier.
+    pub id: String,
+
+    /// Issuer of the deleted VC; its status registry entry is left untouched.
+    pub issuer: Address,
+
+    /// Issuance contract of the deleted VC.
+    pub issuance_contract: Address,
+
+    /// Ledger timestamp of deletion.
+    pub deleted_at: u64,
+}
@@ -15,6 +15,18 @@ pub struct VerifiableCredential {
     /// Issuance contract that can verify/revoke the VC status.
     pub issuance_contract: Address,
 
+    /// Issuer address that signed the issuance. Scopes the status registry entry.
+    pub issuer: Address,
+
     /// Issuer DID (metadata for wallets/UX).
     pub issuer_did: String,
+
+    /// Not valid before this ledger timestamp (seconds), if set.
+    pub valid_from: Option<u64>,
+
+    /// Not valid after this ledger timestamp (seconds), if set.
+    pub valid_until: Option<u64>,
+
+    /// False for soulbound credentials (KYC, diplomas) that `push` must never move.
+    pub transferable: bool,
 }
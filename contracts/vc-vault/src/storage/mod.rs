@@ -1,7 +1,13 @@
 //! Storage layout and helpers. Instance = global config; persistent = per-owner and per-VC.
 
-use crate::model::{VCStatus, VerifiableCredential};
-use soroban_sdk::{contracttype, Address, Env, Map, String, Vec};
+use crate::model::{
+    AdminHandover, AdminProposal, FeeTier, GuardianConfig, IssuerAuthorization, PauseScope,
+    PendingUpgrade, PendingVC, RecoveryRequest, RevocationPolicy, RevocationReason, Role,
+    StatusChange, VCStatus, VCTombstone, VerifiableCredential,
+};
+use soroban_sdk::{
+    contracttype, symbol_short, Address, Env, IntoVal, Map, String, Symbol, TryFromVal, Val, Vec,
+};
 
 /// TTL: extend when remaining < threshold, set to extend_to (ledger counts).
 /// Max per network: ~31_536_000 ledgers (~6 months). Extend to max so credentials
@@ -11,6 +17,9 @@ const INSTANCE_TTL_EXTEND_TO: u32 = 31_536_000;
 const PERSISTENT_TTL_THRESHOLD: u32 = 30_000_000;
 const PERSISTENT_TTL_EXTEND_TO: u32 = 31_536_000;
 
+/// Entries per page of a paged list (vault VC, issuer and inbox indexes). Bounds the size of any single entry.
+pub const INDEX_PAGE_SIZE: u32 = 64;
+
 /// Storage keys. Instance = admin, fees. Persistent = vault metadata, VCs, status.
 #[derive(Clone)]
 #[contracttype]
@@ -20,24 +29,75 @@ pub enum DataKey {
     FeeEnabled,
     FeeTokenContract,
     FeeDest,
+    /// Unused flat fee from the old `set_fee_config`. Cleared by `set_fee_config`.
     FeeAmount,
     FeeAdmin,
     FeeStandard,
     FeeEarly,
     FeeCustom(Address),
+    IssuerTier(Address),
     VaultAdmin(Address),
     VaultDid(Address),
     VaultRevoked(Address),
-    VaultIssuers(Address),
+    VaultRevokedAt(Address),
+    VaultGuardians(Address),
+    VaultRecovery(Address),
+    ReactivationCooldown,
+    VaultIssuer(Address, Address),
+    VaultIssuerCount(Address),
+    VaultIssuerPage(Address, u32),
+    VaultIssuerSlot(Address, Address),
     VaultVC(Address, String),
-    VaultVCIds(Address),
+    VaultVCCount(Address),
+    VaultVCPage(Address, u32),
+    VaultVCSlot(Address, String),
+    VaultVCTombstone(Address, u32, String),
+    RegistryStatus(Address, String),
+    RegistryOwner(Address, String),
+    RevocationPolicy(Address),
+    VaultRevocationPolicy(Address, Address, u32),
+    VaultCap(Address),
+    VaultIssuerQuota(Address, u32, Address),
+    VaultIssuerUsage(Address, Address),
+    VaultInboxTtl(Address),
+    TransferAllowed(Address, u32, Address),
+    /// Bumped by `create_vault`. Scopes per-vault entries that closing cannot enumerate.
+    VaultGeneration(Address),
+    PendingVC(Address, String),
+    PendingCount(Address),
+    PendingPage(Address, u32),
+    PendingSlot(Address, String),
+    /// Pre-scoping registry keys (vc_id only). Read by `migrate_registry`.
     VCStatus(String),
     VCOwner(String),
+    /// Pre-paging vault index (single vector). Read by `migrate_vault_index`.
+    VaultVCIds(Address),
+    /// Pre-entry issuer list (single vector). Read by `migrate_vault_issuers`.
+    VaultIssuers(Address),
     LegacyIssuanceRevocations,
     LegacyIssuanceVCs,
     LegacyVaultVCs(Address),
 }
 
+/// Admin-role keys, kept apart from `DataKey` (at the contracttype variant limit).
+/// Instance = signer set, upgrade timelock, contract admin handover, pause state, roles;
+/// persistent = proposals, vault admin handovers.
+#[derive(Clone)]
+#[contracttype]
+pub enum AdminKey {
+    Signers,
+    Threshold,
+    ProposalCount,
+    Proposal(u32),
+    UpgradeDelay,
+    PendingUpgrade,
+    PendingAdmin,
+    VaultPendingAdmin(Address),
+    Paused(PauseScope),
+    PauseGuardian,
+    Role(Role, Address),
+}
+
 /// Legacy revocation record for migration.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -48,6 +108,48 @@ pub struct LegacyRevocation {
     pub date: String,
 }
 
+/// Registry status layout before ledger-anchored status changes. Read by migrations.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LegacyVCStatus {
+    Valid,
+    Invalid,
+    Revoked(String),
+}
+
+impl LegacyVCStatus {
+    pub fn into_status(self) -> VCStatus {
+        match self {
+            LegacyVCStatus::Valid => VCStatus::Valid,
+            LegacyVCStatus::Invalid => VCStatus::Invalid,
+            LegacyVCStatus::Revoked(date) => legacy_revocation(date),
+        }
+    }
+}
+
+/// Revoked status for a legacy entry: the original date string becomes the note,
+/// timestamp and ledger are 0 (unknown) and the reason is Unspecified.
+pub fn legacy_revocation(date: String) -> VCStatus {
+    VCStatus::Revoked(
+        StatusChange {
+            timestamp: 0,
+            ledger: 0,
+            note: Some(date),
+        },
+        RevocationReason::Unspecified,
+    )
+}
+
+/// VC payload layout before the issuer address was recorded. Read by migrations.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyVerifiableCredential {
+    pub id: String,
+    pub data: String,
+    pub issuance_contract: Address,
+    pub issuer_did: String,
+}
+
 // --- Global config (instance) ---
 
 pub fn has_contract_admin(e: &Env) -> bool {
@@ -62,6 +164,138 @@ pub fn write_contract_admin(e: &Env, admin: &Address) {
     e.storage().instance().set(&DataKey::ContractAdmin, admin);
 }
 
+/// Contract admin signer set. None until first changed; the single `ContractAdmin` applies.
+pub fn read_admin_signers(e: &Env) -> Option<Vec<Address>> {
+    e.storage().instance().get(&AdminKey::Signers)
+}
+
+pub fn write_admin_signers(e: &Env, signers: &Vec<Address>) {
+    e.storage().instance().set(&AdminKey::Signers, signers);
+}
+
+/// Approvals needed to execute an admin proposal. Defaults to 1.
+pub fn read_admin_threshold(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&AdminKey::Threshold)
+        .unwrap_or(1)
+}
+
+pub fn write_admin_threshold(e: &Env, threshold: &u32) {
+    e.storage().instance().set(&AdminKey::Threshold, threshold);
+}
+
+/// Allocate the next admin proposal ID (starting at 1).
+pub fn next_admin_proposal_id(e: &Env) -> u32 {
+    let id: u32 = e
+        .storage()
+        .instance()
+        .get(&AdminKey::ProposalCount)
+        .unwrap_or(0)
+        + 1;
+    e.storage().instance().set(&AdminKey::ProposalCount, &id);
+    id
+}
+
+pub fn read_admin_proposal(e: &Env, id: u32) -> Option<AdminProposal> {
+    e.storage().persistent().get(&AdminKey::Proposal(id))
+}
+
+/// Set or clear (None) an admin proposal.
+pub fn write_admin_proposal(e: &Env, id: u32, proposal: Option<&AdminProposal>) {
+    let key = AdminKey::Proposal(id);
+    match proposal {
+        Some(proposal) => e.storage().persistent().set(&key, proposal),
+        None => e.storage().persistent().remove(&key),
+    }
+}
+
+/// Seconds between announcing and executing an upgrade. None until first set.
+pub fn read_upgrade_delay(e: &Env) -> Option<u64> {
+    e.storage().instance().get(&AdminKey::UpgradeDelay)
+}
+
+pub fn write_upgrade_delay(e: &Env, delay: &u64) {
+    e.storage().instance().set(&AdminKey::UpgradeDelay, delay);
+}
+
+pub fn read_pending_upgrade(e: &Env) -> Option<PendingUpgrade> {
+    e.storage().instance().get(&AdminKey::PendingUpgrade)
+}
+
+/// Set or clear (None) the announced upgrade.
+pub fn write_pending_upgrade(e: &Env, upgrade: Option<&PendingUpgrade>) {
+    match upgrade {
+        Some(upgrade) => e
+            .storage()
+            .instance()
+            .set(&AdminKey::PendingUpgrade, upgrade),
+        None => e.storage().instance().remove(&AdminKey::PendingUpgrade),
+    }
+}
+
+pub fn read_pending_contract_admin(e: &Env) -> Option<AdminHandover> {
+    e.storage().instance().get(&AdminKey::PendingAdmin)
+}
+
+/// Set or clear (None) the proposed contract admin handover.
+pub fn write_pending_contract_admin(e: &Env, handover: Option<&AdminHandover>) {
+    match handover {
+        Some(handover) => e
+            .storage()
+            .instance()
+            .set(&AdminKey::PendingAdmin, handover),
+        None => e.storage().instance().remove(&AdminKey::PendingAdmin),
+    }
+}
+
+/// True while `scope` is paused. Defaults to false.
+pub fn read_paused(e: &Env, scope: PauseScope) -> bool {
+    e.storage()
+        .instance()
+        .get(&AdminKey::Paused(scope))
+        .unwrap_or(false)
+}
+
+pub fn write_paused(e: &Env, scope: PauseScope, paused: bool) {
+    if paused {
+        e.storage().instance().set(&AdminKey::Paused(scope), &true);
+    } else {
+        e.storage().instance().remove(&AdminKey::Paused(scope));
+    }
+}
+
+pub fn read_pause_guardian(e: &Env) -> Option<Address> {
+    e.storage().instance().get(&AdminKey::PauseGuardian)
+}
+
+/// Set or clear (None) the pause guardian.
+pub fn write_pause_guardian(e: &Env, guardian: Option<&Address>) {
+    match guardian {
+        Some(guardian) => e
+            .storage()
+            .instance()
+            .set(&AdminKey::PauseGuardian, guardian),
+        None => e.storage().instance().remove(&AdminKey::PauseGuardian),
+    }
+}
+
+pub fn has_role(e: &Env, role: Role, account: &Address) -> bool {
+    e.storage()
+        .instance()
+        .has(&AdminKey::Role(role, account.clone()))
+}
+
+/// Grant (`granted = true`) or remove `role` for `account`.
+pub fn write_role(e: &Env, role: Role, account: &Address, granted: bool) {
+    let key = AdminKey::Role(role, account.clone());
+    if granted {
+        e.storage().instance().set(&key, &true);
+    } else {
+        e.storage().instance().remove(&key);
+    }
+}
+
 pub fn read_default_issuer_did(e: &Env) -> Option<String> {
     e.storage().instance().get(&DataKey::DefaultIssuerDid)
 }
@@ -71,10 +305,10 @@ pub fn write_default_issuer_did(e: &Env, did: &String) {
 }
 
 pub fn read_fee_enabled(e: &Env) -> bool {
-    match e.storage().instance().get(&DataKey::FeeEnabled) {
-        Some(v) => v,
-        None => false,
-    }
+    e.storage()
+        .instance()
+        .get(&DataKey::FeeEnabled)
+        .unwrap_or_default()
 }
 
 pub fn write_fee_enabled(e: &Env, enabled: &bool) {
@@ -97,12 +331,8 @@ pub fn read_fee_dest(e: &Env) -> Address {
     e.storage().instance().get(&DataKey::FeeDest).unwrap()
 }
 
-pub fn write_fee_amount(e: &Env, amount: &i128) {
-    e.storage().instance().set(&DataKey::FeeAmount, amount);
-}
-
-pub fn read_fee_amount(e: &Env) -> i128 {
-    e.storage().instance().get(&DataKey::FeeAmount).unwrap()
+pub fn remove_legacy_fee_amount(e: &Env) {
+    e.storage().instance().remove(&DataKey::FeeAmount);
 }
 
 /// Fee config status returned by fee_config().
@@ -111,14 +341,12 @@ pub fn read_fee_amount(e: &Env) -> i128 {
 pub struct FeeConfig {
     /// Whether fees are enabled.
     pub enabled: bool,
-    /// Whether token, dest, amount are all set.
+    /// Whether token and dest are both set.
     pub configured: bool,
     /// Token contract address (if configured).
     pub token_contract: Option<Address>,
     /// Fee destination address (if configured).
     pub fee_dest: Option<Address>,
-    /// Fee amount (if configured).
-    pub fee_amount: Option<i128>,
 }
 
 pub fn try_read_fee_token_contract(e: &Env) -> Option<Address> {
@@ -129,22 +357,16 @@ pub fn try_read_fee_dest(e: &Env) -> Option<Address> {
     e.storage().instance().get(&DataKey::FeeDest)
 }
 
-pub fn try_read_fee_amount(e: &Env) -> Option<i128> {
-    e.storage().instance().get(&DataKey::FeeAmount)
-}
-
 pub fn read_fee_config(e: &Env) -> FeeConfig {
     let enabled = read_fee_enabled(e);
     let token_contract = try_read_fee_token_contract(e);
     let fee_dest = try_read_fee_dest(e);
-    let fee_amount = try_read_fee_amount(e);
-    let configured = token_contract.is_some() && fee_dest.is_some() && fee_amount.is_some();
+    let configured = token_contract.is_some() && fee_dest.is_some();
     FeeConfig {
         enabled,
         configured,
         token_contract,
         fee_dest,
-        fee_amount,
     }
 }
 
@@ -192,12 +414,50 @@ pub fn try_read_fee_custom(e: &Env, issuer: &Address) -> Option<i128> {
     e.storage().instance().get(&DataKey::FeeCustom(issuer.clone()))
 }
 
-pub fn read_fee_custom(e: &Env, issuer: &Address) -> i128 {
-    try_read_fee_custom(e, issuer).unwrap_or_else(|| read_fee_amount(e))
+pub fn write_issuer_tier(e: &Env, issuer: &Address, tier: &FeeTier) {
+    e.storage()
+        .instance()
+        .set(&DataKey::IssuerTier(issuer.clone()), tier);
+}
+
+pub fn read_issuer_tier(e: &Env, issuer: &Address) -> FeeTier {
+    e.storage()
+        .instance()
+        .get(&DataKey::IssuerTier(issuer.clone()))
+        .unwrap_or(FeeTier::Standard)
+}
+
+/// Seconds a revoked vault must wait before `reactivate_vault`. Defaults to 0.
+pub fn read_reactivation_cooldown(e: &Env) -> u64 {
+    e.storage()
+        .instance()
+        .get(&DataKey::ReactivationCooldown)
+        .unwrap_or(0)
+}
+
+pub fn write_reactivation_cooldown(e: &Env, cooldown: &u64) {
+    e.storage()
+        .instance()
+        .set(&DataKey::ReactivationCooldown, cooldown);
 }
 
 // --- Vault metadata (persistent) ---
 
+pub fn read_vault_generation(e: &Env, owner: &Address) -> u32 {
+    e.storage()
+        .persistent()
+        .get(&DataKey::VaultGeneration(owner.clone()))
+        .unwrap_or(0)
+}
+
+/// Start a new generation for owner's vault. Kept across `close_vault`.
+pub fn bump_vault_generation(e: &Env, owner: &Address) {
+    let generation = read_vault_generation(e, owner) + 1;
+    e.storage()
+        .persistent()
+        .set(&DataKey::VaultGeneration(owner.clone()), &generation);
+}
+
 pub fn has_vault_admin(e: &Env, owner: &Address) -> bool {
     e.storage().persistent().has(&DataKey::VaultAdmin(owner.clone()))
 }
@@ -238,14 +498,195 @@ pub fn write_vault_revoked(e: &Env, owner: &Address, revoked: &bool) {
         .set(&DataKey::VaultRevoked(owner.clone()), revoked);
 }
 
+/// Ledger timestamp of the last `revoke_vault`; 0 for vaults revoked before it was recorded.
+pub fn read_vault_revoked_at(e: &Env, owner: &Address) -> u64 {
+    e.storage()
+        .persistent()
+        .get(&DataKey::VaultRevokedAt(owner.clone()))
+        .unwrap_or(0)
+}
+
+pub fn write_vault_revoked_at(e: &Env, owner: &Address, revoked_at: &u64) {
+    e.storage()
+        .persistent()
+        .set(&DataKey::VaultRevokedAt(owner.clone()), revoked_at);
+}
+
+pub fn read_guardians(e: &Env, owner: &Address) -> Option<GuardianConfig> {
+    e.storage()
+        .persistent()
+        .get(&DataKey::VaultGuardians(owner.clone()))
+}
+
+/// Set or clear (None) the vault's guardian set.
+pub fn write_guardians(e: &Env, owner: &Address, config: Option<&GuardianConfig>) {
+    let key = DataKey::VaultGuardians(owner.clone());
+    match config {
+        Some(config) => e.storage().persistent().set(&key, config),
+        None => e.storage().persistent().remove(&key),
+    }
+}
+
+/// Open admin recovery requests, one per proposed admin.
+pub fn read_recoveries(e: &Env, owner: &Address) -> Vec<RecoveryRequest> {
+    e.storage()
+        .persistent()
+        .get(&DataKey::VaultRecovery(owner.clone()))
+        .unwrap_or(Vec::new(e))
+}
+
+/// Set the vault's open recovery requests; an empty list clears them.
+pub fn write_recoveries(e: &Env, owner: &Address, requests: &Vec<RecoveryRequest>) {
+    let key = DataKey::VaultRecovery(owner.clone());
+    if requests.is_empty() {
+        e.storage().persistent().remove(&key);
+    } else {
+        e.storage().persistent().set(&key, requests);
+    }
+}
+
+pub fn read_pending_vault_admin(e: &Env, owner: &Address) -> Option<AdminHandover> {
+    e.storage()
+        .persistent()
+        .get(&AdminKey::VaultPendingAdmin(owner.clone()))
+}
+
+/// Set or clear (None) the vault's proposed admin handover.
+pub fn write_pending_vault_admin(e: &Env, owner: &Address, handover: Option<&AdminHandover>) {
+    let key = AdminKey::VaultPendingAdmin(owner.clone());
+    match handover {
+        Some(handover) => e.storage().persistent().set(&key, handover),
+        None => e.storage().persistent().remove(&key),
+    }
+}
+
+/// Remove every per-vault entry that is not enumerated by an index. Call last when closing.
+/// Transfer allowlist entries, tombstones, per-vault revocation policies and issuer quotas are
+/// keyed by unknown addresses/IDs; they are scoped by the vault generation instead, so a vault
+/// created again under the same owner does not see them, and are left to expire.
+pub fn remove_vault_metadata(e: &Env, owner: &Address) {
+    let keys = [
+        DataKey::VaultAdmin(owner.clone()),
+        DataKey::VaultDid(owner.clone()),
+        DataKey::VaultRevoked(owner.clone()),
+        DataKey::VaultRevokedAt(owner.clone()),
+        DataKey::VaultIssuerCount(owner.clone()),
+        DataKey::VaultVCCount(owner.clone()),
+        DataKey::PendingCount(owner.clone()),
+        DataKey::VaultCap(owner.clone()),
+        DataKey::VaultInboxTtl(owner.clone()),
+        DataKey::VaultGuardians(owner.clone()),
+        DataKey::VaultRecovery(owner.clone()),
+        DataKey::LegacyVaultVCs(owner.clone()),
+    ];
+    for key in keys {
+        e.storage().persistent().remove(&key);
+    }
+    write_pending_vault_admin(e, owner, None);
+}
+
 // --- Vault issuers (persistent) ---
 
-pub fn read_vault_issuers(e: &Env, owner: &Address) -> Vec<Address> {
-    e.storage().persistent().get(&DataKey::VaultIssuers(owner.clone())).unwrap()
+// One entry per (owner, issuer), indexed by a paged list like the VC index.
+
+pub fn has_vault_issuer(e: &Env, owner: &Address, issuer: &Address) -> bool {
+    e.storage()
+        .persistent()
+        .has(&DataKey::VaultIssuer(owner.clone(), issuer.clone()))
+}
+
+pub fn read_vault_issuer(
+    e: &Env,
+    owner: &Address,
+    issuer: &Address,
+) -> Option<IssuerAuthorization> {
+    e.storage()
+        .persistent()
+        .get(&DataKey::VaultIssuer(owner.clone(), issuer.clone()))
+}
+
+/// Write authorization and add issuer to the vault's issuer index.
+pub fn write_vault_issuer(e: &Env, owner: &Address, auth: &IssuerAuthorization) {
+    let key = DataKey::VaultIssuer(owner.clone(), auth.issuer.clone());
+    e.storage().persistent().set(&key, auth);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_EXTEND_TO);
+    paged_push(
+        e,
+        &DataKey::VaultIssuerCount(owner.clone()),
+        |page| DataKey::VaultIssuerPage(owner.clone(), page),
+        |issuer: &Address| DataKey::VaultIssuerSlot(owner.clone(), issuer.clone()),
+        &auth.issuer,
+    );
+}
+
+pub fn remove_vault_issuer(e: &Env, owner: &Address, issuer: &Address) {
+    e.storage()
+        .persistent()
+        .remove(&DataKey::VaultIssuer(owner.clone(), issuer.clone()));
+    paged_remove(
+        e,
+        &DataKey::VaultIssuerCount(owner.clone()),
+        |page| DataKey::VaultIssuerPage(owner.clone(), page),
+        |issuer: &Address| DataKey::VaultIssuerSlot(owner.clone(), issuer.clone()),
+        issuer,
+    );
+}
+
+pub fn last_vault_issuer(e: &Env, owner: &Address) -> Option<Address> {
+    paged_last(e, &DataKey::VaultIssuerCount(owner.clone()), |page| {
+        DataKey::VaultIssuerPage(owner.clone(), page)
+    })
+}
+
+pub fn read_vault_issuer_count(e: &Env, owner: &Address) -> u32 {
+    paged_len(e, &DataKey::VaultIssuerCount(owner.clone()))
 }
 
-pub fn write_vault_issuers(e: &Env, owner: &Address, issuers: &Vec<Address>) {
-    e.storage().persistent().set(&DataKey::VaultIssuers(owner.clone()), issuers)
+pub fn read_vault_issuer_page(e: &Env, owner: &Address, page: u32) -> Vec<Address> {
+    paged_read_page(e, &DataKey::VaultIssuerPage(owner.clone(), page))
+}
+
+/// Extend TTL of an issuer's authorization entry and index slot. Call when checking it.
+pub fn extend_vault_issuer_ttl(e: &Env, owner: &Address, issuer: &Address) {
+    let keys = [
+        DataKey::VaultIssuer(owner.clone(), issuer.clone()),
+        DataKey::VaultIssuerSlot(owner.clone(), issuer.clone()),
+    ];
+    for key in keys {
+        if e.storage().persistent().has(&key) {
+            e.storage().persistent().extend_ttl(
+                &key,
+                PERSISTENT_TTL_THRESHOLD,
+                PERSISTENT_TTL_EXTEND_TO,
+            );
+        }
+    }
+}
+
+/// Extend TTL of one vault inbox index page. Call when listing.
+pub fn extend_pending_page_ttl(e: &Env, owner: &Address, page: u32) {
+    let key = DataKey::PendingPage(owner.clone(), page);
+    if e.storage().persistent().has(&key) {
+        e.storage().persistent().extend_ttl(
+            &key,
+            PERSISTENT_TTL_THRESHOLD,
+            PERSISTENT_TTL_EXTEND_TO,
+        );
+    }
+}
+
+/// Extend TTL of one vault issuer index page. Call when listing.
+pub fn extend_vault_issuer_page_ttl(e: &Env, owner: &Address, page: u32) {
+    let key = DataKey::VaultIssuerPage(owner.clone(), page);
+    if e.storage().persistent().has(&key) {
+        e.storage().persistent().extend_ttl(
+            &key,
+            PERSISTENT_TTL_THRESHOLD,
+            PERSISTENT_TTL_EXTEND_TO,
+        );
+    }
 }
 
 // --- VC payloads (persistent) ---
@@ -254,58 +695,469 @@ pub fn write_vault_vc(e: &Env, owner: &Address, vc_id: &String, vc: &VerifiableC
     e.storage().persistent().set(&DataKey::VaultVC(owner.clone(), vc_id.clone()), vc)
 }
 
+/// Decodes both the current layout and `LegacyVerifiableCredential`, which has no `issuer`
+/// field. Payloads without a legacy registry entry are never rewritten by `migrate_registry`,
+/// so legacy ones are read as issued by their issuance contract, matching `migrate`.
 pub fn read_vault_vc(e: &Env, owner: &Address, vc_id: &String) -> Option<VerifiableCredential> {
-    e.storage().persistent().get(&DataKey::VaultVC(owner.clone(), vc_id.clone()))
+    let raw: Val = e
+        .storage()
+        .persistent()
+        .get(&DataKey::VaultVC(owner.clone(), vc_id.clone()))?;
+    let fields = Map::<Symbol, Val>::try_from_val(e, &raw).unwrap();
+    if fields.contains_key(symbol_short!("issuer")) {
+        return Some(VerifiableCredential::try_from_val(e, &raw).unwrap());
+    }
+    let legacy = LegacyVerifiableCredential::try_from_val(e, &raw).unwrap();
+    Some(VerifiableCredential {
+        id: legacy.id,
+        data: legacy.data,
+        issuer: legacy.issuance_contract.clone(),
+        issuance_contract: legacy.issuance_contract,
+        issuer_did: legacy.issuer_did,
+        valid_from: None,
+        valid_until: None,
+        transferable: true,
+    })
+}
+
+pub fn has_vault_vc(e: &Env, owner: &Address, vc_id: &String) -> bool {
+    e.storage()
+        .persistent()
+        .has(&DataKey::VaultVC(owner.clone(), vc_id.clone()))
 }
 
 pub fn remove_vault_vc(e: &Env, owner: &Address, vc_id: &String) {
     e.storage().persistent().remove(&DataKey::VaultVC(owner.clone(), vc_id.clone()));
 }
 
+fn vc_tombstone_key(e: &Env, owner: &Address, vc_id: &String) -> DataKey {
+    DataKey::VaultVCTombstone(owner.clone(), read_vault_generation(e, owner), vc_id.clone())
+}
+
+pub fn has_vc_tombstone(e: &Env, owner: &Address, vc_id: &String) -> bool {
+    e.storage()
+        .persistent()
+        .has(&vc_tombstone_key(e, owner, vc_id))
+}
+
+pub fn read_vc_tombstone(e: &Env, owner: &Address, vc_id: &String) -> Option<VCTombstone> {
+    e.storage()
+        .persistent()
+        .get(&vc_tombstone_key(e, owner, vc_id))
+}
+
+pub fn write_vc_tombstone(e: &Env, owner: &Address, tombstone: &VCTombstone) {
+    let key = vc_tombstone_key(e, owner, &tombstone.id);
+    e.storage().persistent().set(&key, tombstone);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_EXTEND_TO);
+}
+
+// --- Vault VC index (persistent, paged) ---
+// IDs live in pages of INDEX_PAGE_SIZE; VaultVCSlot(owner, id) holds each ID's position so
+// membership and removal never scan. Removal moves the last ID into the freed slot.
+
+pub fn read_vault_vc_count(e: &Env, owner: &Address) -> u32 {
+    paged_len(e, &DataKey::VaultVCCount(owner.clone()))
+}
+
+pub fn last_vault_vc_id(e: &Env, owner: &Address) -> Option<String> {
+    paged_last(e, &DataKey::VaultVCCount(owner.clone()), |page| {
+        DataKey::VaultVCPage(owner.clone(), page)
+    })
+}
+
+pub fn read_vault_vc_page(e: &Env, owner: &Address, page: u32) -> Vec<String> {
+    paged_read_page(e, &DataKey::VaultVCPage(owner.clone(), page))
+}
+
+/// All IDs in index order. Reads every page; prefer `read_vault_vc_page` for large vaults.
 pub fn read_vault_vc_ids(e: &Env, owner: &Address) -> Vec<String> {
-    match e.storage().persistent().get(&DataKey::VaultVCIds(owner.clone())) {
-        Some(v) => v,
-        None => Vec::new(e),
+    let count = read_vault_vc_count(e, owner);
+    let mut ids = Vec::new(e);
+    for page in 0..count.div_ceil(INDEX_PAGE_SIZE) {
+        ids.append(&read_vault_vc_page(e, owner, page));
     }
+    ids
 }
 
-pub fn write_vault_vc_ids(e: &Env, owner: &Address, ids: &Vec<String>) {
-    e.storage().persistent().set(&DataKey::VaultVCIds(owner.clone()), ids)
+pub fn append_vault_vc_id(e: &Env, owner: &Address, vc_id: &String) {
+    paged_push(
+        e,
+        &DataKey::VaultVCCount(owner.clone()),
+        |page| DataKey::VaultVCPage(owner.clone(), page),
+        |id: &String| DataKey::VaultVCSlot(owner.clone(), id.clone()),
+        vc_id,
+    );
 }
 
-pub fn append_vault_vc_id(e: &Env, owner: &Address, vc_id: &String) {
-    let mut ids = read_vault_vc_ids(e, owner);
-    if !ids.contains(vc_id.clone()) {
-        ids.push_front(vc_id.clone());
-        write_vault_vc_ids(e, owner, &ids);
+pub fn remove_vault_vc_id(e: &Env, owner: &Address, vc_id: &String) {
+    paged_remove(
+        e,
+        &DataKey::VaultVCCount(owner.clone()),
+        |page| DataKey::VaultVCPage(owner.clone(), page),
+        |id: &String| DataKey::VaultVCSlot(owner.clone(), id.clone()),
+        vc_id,
+    );
+}
+
+// --- Paged list primitives ---
+
+fn paged_len(e: &Env, len_key: &DataKey) -> u32 {
+    e.storage().persistent().get(len_key).unwrap_or(0)
+}
+
+fn paged_read_page<T>(e: &Env, page_key: &DataKey) -> Vec<T>
+where
+    T: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+{
+    e.storage()
+        .persistent()
+        .get(page_key)
+        .unwrap_or_else(|| Vec::new(e))
+}
+
+/// Last entry, if any. Removing it with `paged_remove` touches a single page.
+fn paged_last<T, P>(e: &Env, len_key: &DataKey, page_key: P) -> Option<T>
+where
+    T: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+    P: Fn(u32) -> DataKey,
+{
+    let len = paged_len(e, len_key);
+    if len == 0 {
+        return None;
     }
+    let page: Vec<T> = paged_read_page(e, &page_key((len - 1) / INDEX_PAGE_SIZE));
+    page.last()
 }
 
-pub fn remove_vault_vc_id(e: &Env, owner: &Address, vc_id: &String) {
-    let mut ids = read_vault_vc_ids(e, owner);
-    if let Some(idx) = ids.first_index_of(vc_id.clone()) {
-        ids.remove(idx);
-        write_vault_vc_ids(e, owner, &ids);
+/// Append `item` unless its slot key exists. Touches one page.
+fn paged_push<T, P, S>(e: &Env, len_key: &DataKey, page_key: P, slot_key: S, item: &T)
+where
+    T: IntoVal<Env, Val> + TryFromVal<Env, Val> + Clone,
+    P: Fn(u32) -> DataKey,
+    S: Fn(&T) -> DataKey,
+{
+    let storage = e.storage().persistent();
+    if storage.has(&slot_key(item)) {
+        return;
     }
+    let slot = paged_len(e, len_key);
+    let key = page_key(slot / INDEX_PAGE_SIZE);
+    let mut page: Vec<T> = paged_read_page(e, &key);
+    page.push_back(item.clone());
+    storage.set(&key, &page);
+    storage.set(&slot_key(item), &slot);
+    storage.set(len_key, &(slot + 1));
 }
 
-pub fn write_vc_status(e: &Env, vc_id: &String, status: &VCStatus) {
-    e.storage().persistent().set(&DataKey::VCStatus(vc_id.clone()), status)
+/// Remove `item` by moving the last entry into its slot. Touches at most two pages, at the cost
+/// of order: positions (and so listing cursors) are not stable across removals.
+fn paged_remove<T, P, S>(e: &Env, len_key: &DataKey, page_key: P, slot_key: S, item: &T)
+where
+    T: IntoVal<Env, Val> + TryFromVal<Env, Val> + Clone,
+    P: Fn(u32) -> DataKey,
+    S: Fn(&T) -> DataKey,
+{
+    let storage = e.storage().persistent();
+    let slot: u32 = match storage.get(&slot_key(item)) {
+        Some(slot) => slot,
+        None => return,
+    };
+    let last = paged_len(e, len_key) - 1;
+    let last_key = page_key(last / INDEX_PAGE_SIZE);
+    let mut last_page: Vec<T> = paged_read_page(e, &last_key);
+    let moved = last_page.pop_back_unchecked();
+    if slot != last {
+        if slot / INDEX_PAGE_SIZE == last / INDEX_PAGE_SIZE {
+            last_page.set(slot % INDEX_PAGE_SIZE, moved.clone());
+        } else {
+            let key = page_key(slot / INDEX_PAGE_SIZE);
+            let mut page: Vec<T> = paged_read_page(e, &key);
+            page.set(slot % INDEX_PAGE_SIZE, moved.clone());
+            storage.set(&key, &page);
+        }
+        storage.set(&slot_key(&moved), &slot);
+    }
+    if last_page.is_empty() {
+        storage.remove(&last_key);
+    } else {
+        storage.set(&last_key, &last_page);
+    }
+    storage.remove(&slot_key(item));
+    storage.set(len_key, &last);
 }
 
-pub fn read_vc_status(e: &Env, vc_id: &String) -> VCStatus {
+// --- Status registry (persistent, scoped by issuer) ---
+
+pub fn write_vc_status(e: &Env, issuer: &Address, vc_id: &String, status: &VCStatus) {
+    e.storage().persistent().set(
+        &DataKey::RegistryStatus(issuer.clone(), vc_id.clone()),
+        status,
+    )
+}
+
+/// Whether `issuer` has its own registry entry for `vc_id` (legacy entries not counted).
+pub fn has_vc_status(e: &Env, issuer: &Address, vc_id: &String) -> bool {
     e.storage()
         .persistent()
-        .get(&DataKey::VCStatus(vc_id.clone()))
-        .unwrap_or(VCStatus::Invalid)
+        .has(&DataKey::RegistryStatus(issuer.clone(), vc_id.clone()))
+}
+
+/// Falls back to the pre-scoping `VCStatus(vc_id)` entry until `migrate_registry` moves it.
+/// Legacy entries were written by this contract as issuer, which is how `read_vault_vc` reads
+/// legacy payloads.
+pub fn read_vc_status(e: &Env, issuer: &Address, vc_id: &String) -> VCStatus {
+    let status = e
+        .storage()
+        .persistent()
+        .get(&DataKey::RegistryStatus(issuer.clone(), vc_id.clone()));
+    match status {
+        Some(status) => status,
+        None if *issuer == e.current_contract_address() => read_legacy_vc_status(e, vc_id)
+            .map(LegacyVCStatus::into_status)
+            .unwrap_or(VCStatus::Invalid),
+        None => VCStatus::Invalid,
+    }
+}
+
+pub fn write_vc_owner(e: &Env, issuer: &Address, vc_id: &String, owner: &Address) {
+    e.storage().persistent().set(
+        &DataKey::RegistryOwner(issuer.clone(), vc_id.clone()),
+        owner,
+    )
+}
+
+pub fn read_vc_owner(e: &Env, issuer: &Address, vc_id: &String) -> Option<Address> {
+    e.storage()
+        .persistent()
+        .get(&DataKey::RegistryOwner(issuer.clone(), vc_id.clone()))
+}
+
+pub fn remove_vc_owner(e: &Env, issuer: &Address, vc_id: &String) {
+    e.storage()
+        .persistent()
+        .remove(&DataKey::RegistryOwner(issuer.clone(), vc_id.clone()));
+}
+
+pub fn remove_vc_status(e: &Env, issuer: &Address, vc_id: &String) {
+    e.storage()
+        .persistent()
+        .remove(&DataKey::RegistryStatus(issuer.clone(), vc_id.clone()));
+}
+
+// --- Revocation policy (persistent) ---
+
+pub fn write_revocation_policy(e: &Env, issuer: &Address, policy: &RevocationPolicy) {
+    let key = DataKey::RevocationPolicy(issuer.clone());
+    e.storage().persistent().set(&key, policy);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_EXTEND_TO);
+}
+
+pub fn read_revocation_policy(e: &Env, issuer: &Address) -> Option<RevocationPolicy> {
+    e.storage()
+        .persistent()
+        .get(&DataKey::RevocationPolicy(issuer.clone()))
+}
+
+pub fn write_vault_revocation_policy(
+    e: &Env,
+    issuer: &Address,
+    owner: &Address,
+    policy: &RevocationPolicy,
+) {
+    let key = DataKey::VaultRevocationPolicy(
+        issuer.clone(),
+        owner.clone(),
+        read_vault_generation(e, owner),
+    );
+    e.storage().persistent().set(&key, policy);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_EXTEND_TO);
+}
+
+pub fn read_vault_revocation_policy(
+    e: &Env,
+    issuer: &Address,
+    owner: &Address,
+) -> Option<RevocationPolicy> {
+    e.storage()
+        .persistent()
+        .get(&DataKey::VaultRevocationPolicy(
+            issuer.clone(),
+            owner.clone(),
+            read_vault_generation(e, owner),
+        ))
+}
+
+// --- Vault quotas (persistent) ---
+
+pub fn read_vault_cap(e: &Env, owner: &Address) -> Option<u32> {
+    e.storage()
+        .persistent()
+        .get(&DataKey::VaultCap(owner.clone()))
 }
 
-pub fn write_vc_owner(e: &Env, vc_id: &String, owner: &Address) {
-    e.storage().persistent().set(&DataKey::VCOwner(vc_id.clone()), owner)
+/// Set or clear (None) the vault-wide credential cap.
+pub fn write_vault_cap(e: &Env, owner: &Address, cap: Option<u32>) {
+    let key = DataKey::VaultCap(owner.clone());
+    match cap {
+        Some(cap) => e.storage().persistent().set(&key, &cap),
+        None => e.storage().persistent().remove(&key),
+    }
 }
 
-pub fn read_vc_owner(e: &Env, vc_id: &String) -> Option<Address> {
-    e.storage().persistent().get(&DataKey::VCOwner(vc_id.clone()))
+fn issuer_quota_key(e: &Env, owner: &Address, issuer: &Address) -> DataKey {
+    DataKey::VaultIssuerQuota(owner.clone(), read_vault_generation(e, owner), issuer.clone())
+}
+
+pub fn read_issuer_quota(e: &Env, owner: &Address, issuer: &Address) -> Option<u32> {
+    e.storage()
+        .persistent()
+        .get(&issuer_quota_key(e, owner, issuer))
+}
+
+/// Set or clear (None) how many credentials `issuer` may hold in the vault.
+pub fn write_issuer_quota(e: &Env, owner: &Address, issuer: &Address, quota: Option<u32>) {
+    let key = issuer_quota_key(e, owner, issuer);
+    match quota {
+        Some(quota) => {
+            e.storage().persistent().set(&key, &quota);
+            e.storage().persistent().extend_ttl(
+                &key,
+                PERSISTENT_TTL_THRESHOLD,
+                PERSISTENT_TTL_EXTEND_TO,
+            );
+        }
+        None => e.storage().persistent().remove(&key),
+    }
+}
+
+/// Credentials from `issuer` currently in the vault (counted since quotas were introduced).
+pub fn read_issuer_usage(e: &Env, owner: &Address, issuer: &Address) -> u32 {
+    e.storage()
+        .persistent()
+        .get(&DataKey::VaultIssuerUsage(owner.clone(), issuer.clone()))
+        .unwrap_or(0)
+}
+
+pub fn write_issuer_usage(e: &Env, owner: &Address, issuer: &Address, usage: u32) {
+    let key = DataKey::VaultIssuerUsage(owner.clone(), issuer.clone());
+    if usage == 0 {
+        e.storage().persistent().remove(&key);
+    } else {
+        e.storage().persistent().set(&key, &usage);
+        e.storage().persistent().extend_ttl(
+            &key,
+            PERSISTENT_TTL_THRESHOLD,
+            PERSISTENT_TTL_EXTEND_TO,
+        );
+    }
+}
+
+// --- Transfer allowlist (persistent) ---
+// TransferAllowed(recipient, generation, sender): recipient accepts pushes from sender without
+// signing.
+
+fn transfer_allowed_key(e: &Env, recipient: &Address, sender: &Address) -> DataKey {
+    DataKey::TransferAllowed(
+        recipient.clone(),
+        read_vault_generation(e, recipient),
+        sender.clone(),
+    )
+}
+
+pub fn has_transfer_allowed(e: &Env, recipient: &Address, sender: &Address) -> bool {
+    e.storage()
+        .persistent()
+        .has(&transfer_allowed_key(e, recipient, sender))
+}
+
+pub fn write_transfer_allowed(e: &Env, recipient: &Address, sender: &Address, allowed: bool) {
+    let key = transfer_allowed_key(e, recipient, sender);
+    if allowed {
+        e.storage().persistent().set(&key, &true);
+        e.storage().persistent().extend_ttl(
+            &key,
+            PERSISTENT_TTL_THRESHOLD,
+            PERSISTENT_TTL_EXTEND_TO,
+        );
+    } else {
+        e.storage().persistent().remove(&key);
+    }
+}
+
+// --- Vault inbox (persistent, paged) ---
+
+/// Seconds a pending credential stays acceptable. None = inbox off (deliver directly).
+pub fn read_inbox_ttl(e: &Env, owner: &Address) -> Option<u64> {
+    e.storage()
+        .persistent()
+        .get(&DataKey::VaultInboxTtl(owner.clone()))
+}
+
+pub fn write_inbox_ttl(e: &Env, owner: &Address, ttl: Option<u64>) {
+    let key = DataKey::VaultInboxTtl(owner.clone());
+    match ttl {
+        Some(ttl) => e.storage().persistent().set(&key, &ttl),
+        None => e.storage().persistent().remove(&key),
+    }
+}
+
+pub fn has_pending_vc(e: &Env, owner: &Address, vc_id: &String) -> bool {
+    e.storage()
+        .persistent()
+        .has(&DataKey::PendingVC(owner.clone(), vc_id.clone()))
+}
+
+pub fn read_pending_vc(e: &Env, owner: &Address, vc_id: &String) -> Option<PendingVC> {
+    e.storage()
+        .persistent()
+        .get(&DataKey::PendingVC(owner.clone(), vc_id.clone()))
+}
+
+/// Write pending credential and add it to the vault's inbox index.
+pub fn write_pending_vc(e: &Env, owner: &Address, pending: &PendingVC) {
+    let key = DataKey::PendingVC(owner.clone(), pending.vc.id.clone());
+    e.storage().persistent().set(&key, pending);
+    paged_push(
+        e,
+        &DataKey::PendingCount(owner.clone()),
+        |page| DataKey::PendingPage(owner.clone(), page),
+        |id: &String| DataKey::PendingSlot(owner.clone(), id.clone()),
+        &pending.vc.id,
+    );
+}
+
+pub fn remove_pending_vc(e: &Env, owner: &Address, vc_id: &String) {
+    e.storage()
+        .persistent()
+        .remove(&DataKey::PendingVC(owner.clone(), vc_id.clone()));
+    paged_remove(
+        e,
+        &DataKey::PendingCount(owner.clone()),
+        |page| DataKey::PendingPage(owner.clone(), page),
+        |id: &String| DataKey::PendingSlot(owner.clone(), id.clone()),
+        vc_id,
+    );
+}
+
+pub fn last_pending_id(e: &Env, owner: &Address) -> Option<String> {
+    paged_last(e, &DataKey::PendingCount(owner.clone()), |page| {
+        DataKey::PendingPage(owner.clone(), page)
+    })
+}
+
+pub fn read_pending_count(e: &Env, owner: &Address) -> u32 {
+    paged_len(e, &DataKey::PendingCount(owner.clone()))
+}
+
+pub fn read_pending_page(e: &Env, owner: &Address, page: u32) -> Vec<String> {
+    paged_read_page(e, &DataKey::PendingPage(owner.clone(), page))
 }
 
 // --- TTL extensions ---
@@ -318,13 +1170,22 @@ pub fn extend_instance_ttl(e: &Env) {
 }
 
 /// Extend TTL of vault keys. Call when reading/writing vault.
+/// Also extends every page of the VC, issuer and inbox indexes (one entry per 64 IDs), so an
+/// index never expires ahead of the entries it lists.
 pub fn extend_vault_ttl(e: &Env, owner: &Address) {
     let keys = [
         DataKey::VaultAdmin(owner.clone()),
         DataKey::VaultDid(owner.clone()),
         DataKey::VaultRevoked(owner.clone()),
-        DataKey::VaultIssuers(owner.clone()),
-        DataKey::VaultVCIds(owner.clone()),
+        DataKey::VaultRevokedAt(owner.clone()),
+        DataKey::VaultIssuerCount(owner.clone()),
+        DataKey::VaultVCCount(owner.clone()),
+        DataKey::VaultCap(owner.clone()),
+        DataKey::VaultInboxTtl(owner.clone()),
+        DataKey::PendingCount(owner.clone()),
+        DataKey::VaultGuardians(owner.clone()),
+        DataKey::VaultRecovery(owner.clone()),
+        DataKey::VaultGeneration(owner.clone()),
     ];
     for key in keys {
         if e.storage().persistent().has(&key) {
@@ -333,28 +1194,54 @@ pub fn extend_vault_ttl(e: &Env, owner: &Address) {
                 .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_EXTEND_TO);
         }
     }
+    for page in 0..read_vault_vc_count(e, owner).div_ceil(INDEX_PAGE_SIZE) {
+        extend_vault_vc_page_ttl(e, owner, page);
+    }
+    for page in 0..read_vault_issuer_count(e, owner).div_ceil(INDEX_PAGE_SIZE) {
+        extend_vault_issuer_page_ttl(e, owner, page);
+    }
+    for page in 0..read_pending_count(e, owner).div_ceil(INDEX_PAGE_SIZE) {
+        extend_pending_page_ttl(e, owner, page);
+    }
 }
 
-/// Extend TTL of VC payload, index, status, owner. Call when touching a VC.
-pub fn extend_vc_ttl(e: &Env, owner: &Address, vc_id: &String) {
+/// Extend TTL of VC payload, index slot and page, status, owner. Call when touching a VC.
+pub fn extend_vc_ttl(e: &Env, owner: &Address, issuer: &Address, vc_id: &String) {
     let vc_key = DataKey::VaultVC(owner.clone(), vc_id.clone());
-    let ids_key = DataKey::VaultVCIds(owner.clone());
-    let status_key = DataKey::VCStatus(vc_id.clone());
-    let owner_key = DataKey::VCOwner(vc_id.clone());
-    for key in [&vc_key, &ids_key, &status_key, &owner_key] {
+    let slot_key = DataKey::VaultVCSlot(owner.clone(), vc_id.clone());
+    let status_key = DataKey::RegistryStatus(issuer.clone(), vc_id.clone());
+    let owner_key = DataKey::RegistryOwner(issuer.clone(), vc_id.clone());
+    for key in [&vc_key, &slot_key, &status_key, &owner_key] {
         if e.storage().persistent().has(key) {
-            e.storage()
-                .persistent()
-                .extend_ttl(key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_EXTEND_TO);
+            e.storage().persistent().extend_ttl(
+                key,
+                PERSISTENT_TTL_THRESHOLD,
+                PERSISTENT_TTL_EXTEND_TO,
+            );
         }
     }
+    if let Some(slot) = e.storage().persistent().get::<_, u32>(&slot_key) {
+        extend_vault_vc_page_ttl(e, owner, slot / INDEX_PAGE_SIZE);
+    }
+}
+
+/// Extend TTL of one vault index page. Call when listing.
+pub fn extend_vault_vc_page_ttl(e: &Env, owner: &Address, page: u32) {
+    let key = DataKey::VaultVCPage(owner.clone(), page);
+    if e.storage().persistent().has(&key) {
+        e.storage().persistent().extend_ttl(
+            &key,
+            PERSISTENT_TTL_THRESHOLD,
+            PERSISTENT_TTL_EXTEND_TO,
+        );
+    }
 }
 
 /// Extend TTL of VC status/owner only. Call from revoke flow.
-pub fn extend_vc_status_ttl(e: &Env, vc_id: &String) {
+pub fn extend_vc_status_ttl(e: &Env, issuer: &Address, vc_id: &String) {
     for key in [
-        DataKey::VCStatus(vc_id.clone()),
-        DataKey::VCOwner(vc_id.clone()),
+        DataKey::RegistryStatus(issuer.clone(), vc_id.clone()),
+        DataKey::RegistryOwner(issuer.clone(), vc_id.clone()),
     ] {
         if e.storage().persistent().has(&key) {
             e.storage()
@@ -379,13 +1266,74 @@ pub fn read_legacy_issuance_revocations(e: &Env) -> Map<String, LegacyRevocation
 }
 
 pub fn remove_legacy_issuance_revocations(e: &Env) {
-    e.storage().persistent().remove(&DataKey::LegacyIssuanceRevocations);
+    e.storage()
+        .persistent()
+        .remove(&DataKey::LegacyIssuanceRevocations);
 }
 
-pub fn read_legacy_vault_vcs(e: &Env, owner: &Address) -> Option<Vec<VerifiableCredential>> {
-    e.storage().persistent().get(&DataKey::LegacyVaultVCs(owner.clone()))
+pub fn read_legacy_vault_vcs(e: &Env, owner: &Address) -> Option<Vec<LegacyVerifiableCredential>> {
+    e.storage()
+        .persistent()
+        .get(&DataKey::LegacyVaultVCs(owner.clone()))
 }
 
 pub fn remove_legacy_vault_vcs(e: &Env, owner: &Address) {
-    e.storage().persistent().remove(&DataKey::LegacyVaultVCs(owner.clone()));
+    e.storage()
+        .persistent()
+        .remove(&DataKey::LegacyVaultVCs(owner.clone()));
+}
+
+pub fn read_legacy_vc_status(e: &Env, vc_id: &String) -> Option<LegacyVCStatus> {
+    e.storage()
+        .persistent()
+        .get(&DataKey::VCStatus(vc_id.clone()))
+}
+
+pub fn remove_legacy_vc_status(e: &Env, vc_id: &String) {
+    e.storage()
+        .persistent()
+        .remove(&DataKey::VCStatus(vc_id.clone()));
+}
+
+pub fn read_legacy_vc_owner(e: &Env, vc_id: &String) -> Option<Address> {
+    e.storage()
+        .persistent()
+        .get(&DataKey::VCOwner(vc_id.clone()))
+}
+
+pub fn remove_legacy_vc_owner(e: &Env, vc_id: &String) {
+    e.storage()
+        .persistent()
+        .remove(&DataKey::VCOwner(vc_id.clone()));
+}
+
+/// Whether owner still has a single-vector VC index or issuer list awaiting migration.
+pub fn has_legacy_vault_index(e: &Env, owner: &Address) -> bool {
+    let storage = e.storage().persistent();
+    storage.has(&DataKey::VaultVCIds(owner.clone()))
+        || storage.has(&DataKey::VaultIssuers(owner.clone()))
+}
+
+pub fn read_legacy_vault_vc_ids(e: &Env, owner: &Address) -> Option<Vec<String>> {
+    e.storage()
+        .persistent()
+        .get(&DataKey::VaultVCIds(owner.clone()))
+}
+
+pub fn remove_legacy_vault_vc_ids(e: &Env, owner: &Address) {
+    e.storage()
+        .persistent()
+        .remove(&DataKey::VaultVCIds(owner.clone()));
+}
+
+pub fn read_legacy_vault_issuers(e: &Env, owner: &Address) -> Option<Vec<Address>> {
+    e.storage()
+        .persistent()
+        .get(&DataKey::VaultIssuers(owner.clone()))
+}
+
+pub fn remove_legacy_vault_issuers(e: &Env, owner: &Address) {
+    e.storage()
+        .persistent()
+        .remove(&DataKey::VaultIssuers(owner.clone()));
 }
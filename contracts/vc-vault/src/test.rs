@@ -1,34 +1,186 @@
 //! Unit tests for VC Vault contract.
 
+extern crate std;
+
 use crate::contract::{VcVaultContract, VcVaultContractClient};
-use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+use crate::error::ContractError;
+use crate::model::{AdminAction, FeeTier, PauseScope, Role, VCPage};
+use crate::model::{
+    IssuerAuthorization, RevocationPolicy, RevocationReason, StatusChange, VCFilter, VCStatus,
+};
+use crate::storage::{self, DataKey, LegacyVCStatus, LegacyVerifiableCredential};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events, Ledger, MockAuth, MockAuthInvoke},
+    token, vec, Address, BytesN, Env, IntoVal, String, Val, Vec,
+};
+use std::string::ToString;
+
+/// Salt the test contract is "deployed" with; `initialize` needs it alongside the admin.
+fn deploy_salt(env: &Env) -> BytesN<32> {
+    BytesN::from_array(env, &[1; 32])
+}
 
-/// Create env, admin, issuer, contract, and client for tests.
+/// Create env, admin, issuer, contract, and client for tests. The contract sits at the address
+/// `admin` would deploy it to with `deploy_salt`.
 fn setup() -> (Env, Address, Address, Address, VcVaultContractClient<'static>) {
     let env = Env::default();
     env.mock_all_auths();
     let admin = Address::generate(&env);
     let issuer = Address::generate(&env);
-    let contract_id = env.register_contract(None, VcVaultContract);
+    let contract_id = env
+        .deployer()
+        .with_address(admin.clone(), deploy_salt(&env))
+        .deployed_address();
+    env.register_contract(Some(&contract_id), VcVaultContract);
     let client = VcVaultContractClient::new(&env, &contract_id);
     (env, admin, issuer, contract_id, client)
 }
 
+/// Topics and data of the most recent contract event.
+fn last_event(env: &Env, contract_id: &Address) -> (Vec<Val>, Val) {
+    let (addr, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(&addr, contract_id);
+    (topics, data)
+}
+
+/// Register a Stellar Asset Contract, mint `amount` to `holder`, enable fees paid to a fresh address.
+/// Returns (token client, fee destination).
+fn setup_fee_token<'a>(
+    env: &Env,
+    client: &VcVaultContractClient<'a>,
+    holder: &Address,
+    amount: i128,
+) -> (token::Client<'a>, Address) {
+    let token_admin = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(token_admin);
+    token::StellarAssetClient::new(env, &sac.address()).mint(holder, &amount);
+    let fee_dest = Address::generate(env);
+    client.set_fee_config(&sac.address(), &fee_dest);
+    client.set_fee_enabled(&true);
+    (token::Client::new(env, &sac.address()), fee_dest)
+}
+
+/// Create a vault with `issuer` authorized and issue a VC with the given ID. Returns the owner.
+fn issue_one(
+    env: &Env,
+    client: &VcVaultContractClient,
+    contract_id: &Address,
+    issuer: &Address,
+    vc_id: &str,
+) -> Address {
+    let owner = Address::generate(env);
+    client.create_vault(
+        &owner,
+        &String::from_str(env, "did:pkh:stellar:testnet:OWNER"),
+    );
+    client.authorize_issuer(&owner, issuer, &None, &None, &vec![env]);
+    client.issue(
+        &owner,
+        &String::from_str(env, vc_id),
+        &String::from_str(env, "<ciphertext>"),
+        contract_id,
+        issuer,
+        &String::from_str(env, "did:pkh:stellar:testnet:ISSUER"),
+        &None,
+        &None,
+        &true,
+    );
+    owner
+}
+
+/// Filter that matches every VC.
+fn no_filter() -> VCFilter {
+    VCFilter {
+        issuer_did: None,
+        issuance_contract: None,
+        status: None,
+    }
+}
+
+/// Issue `count` VCs ("vc-0", "vc-1", ...) into `owner`'s vault. Even IDs use issuer DID "did:even",
+/// odd IDs "did:odd". Each issuance runs with a fresh default budget, as its own transaction would.
+fn issue_many(
+    env: &Env,
+    client: &VcVaultContractClient,
+    contract_id: &Address,
+    owner: &Address,
+    issuer: &Address,
+    count: u32,
+) {
+    let vc_data = String::from_str(env, "<ciphertext>");
+    let even_did = String::from_str(env, "did:even");
+    let odd_did = String::from_str(env, "did:odd");
+    for i in 0..count {
+        let vc_id = String::from_str(env, &std::format!("vc-{i}"));
+        let issuer_did = if i % 2 == 0 { &even_did } else { &odd_did };
+        env.budget().reset_default();
+        client.issue(
+            owner,
+            &vc_id,
+            &vc_data,
+            contract_id,
+            issuer,
+            issuer_did,
+            &None,
+            &None,
+            &true,
+        );
+    }
+}
+
 #[test]
 fn test_version() {
     let (_env, _admin, _issuer, _contract_id, client) = setup();
     let v = client.version();
-    assert!(v.len() > 0);
+    assert!(!v.is_empty());
 }
 
 #[test]
 fn test_initialize_and_create_vault() {
     let (env, admin, _issuer, _contract_id, client) = setup();
     let default_did = String::from_str(&env, "did:acta:default");
-    client.initialize(&admin, &default_did);
+    client.initialize(&admin, &default_did, &deploy_salt(&env));
+    let owner = Address::generate(&env);
+    let did_uri = String::from_str(&env, "did:pkh:stellar:testnet:OWNER");
+    client.create_vault(&owner, &did_uri);
+}
+
+#[test]
+fn test_initialize_by_non_deployer_fails() {
+    let (env, admin, _issuer, _contract_id, client) = setup();
+    let default_did = String::from_str(&env, "did:acta:default");
+    // A front-runner cannot claim the contract, with its own salt or the deployer's.
+    let attacker = Address::generate(&env);
+    assert!(client
+        .try_initialize(&attacker, &default_did, &deploy_salt(&env))
+        .is_err());
+    assert!(client
+        .try_initialize(&attacker, &default_did, &BytesN::from_array(&env, &[2; 32]))
+        .is_err());
+    // Nor can the deployer with a salt that does not derive this address.
+    assert!(client
+        .try_initialize(&admin, &default_did, &BytesN::from_array(&env, &[2; 32]))
+        .is_err());
+    client.initialize(&admin, &default_did, &deploy_salt(&env));
+    assert_eq!(client.get_admin_signers(), vec![&env, admin]);
+}
+
+#[test]
+fn test_create_vault_before_initialize_fails() {
+    let (env, admin, _issuer, _contract_id, client) = setup();
     let owner = Address::generate(&env);
     let did_uri = String::from_str(&env, "did:pkh:stellar:testnet:OWNER");
+    // The first vault owner used to become contract admin here.
+    assert!(client.try_create_vault(&owner, &did_uri).is_err());
+    assert!(client.try_get_admin_signers().is_err());
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
     client.create_vault(&owner, &did_uri);
+    assert_eq!(client.get_admin_signers(), vec![&env, admin]);
 }
 
 #[test]
@@ -36,50 +188,134 @@ fn test_initialize_and_create_vault() {
 fn test_initialize_twice_panics() {
     let (env, admin, _issuer, _contract_id, client) = setup();
     let default_did = String::from_str(&env, "did:acta:default");
-    client.initialize(&admin, &default_did);
-    client.initialize(&admin, &default_did);
+    client.initialize(&admin, &default_did, &deploy_salt(&env));
+    client.initialize(&admin, &default_did, &deploy_salt(&env));
 }
 
 #[test]
-fn test_set_contract_admin() {
-    let (env, admin, _issuer, _contract_id, client) = setup();
-    client.initialize(&admin, &String::from_str(&env, "did:acta:default"));
+fn test_contract_admin_handover() {
+    let (env, admin, _issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
     let new_admin = Address::generate(&env);
-    client.set_contract_admin(&new_admin);
-    let another_admin = Address::generate(&env);
-    client.set_contract_admin(&another_admin);
+    client.propose_contract_admin(&new_admin);
+    let (topics, _) = last_event(&env, &contract_id);
+    assert_eq!(
+        topics,
+        (symbol_short!("contract"), symbol_short!("adm_prop")).into_val(&env)
+    );
+    // Nothing changes until the new admin accepts.
+    assert_eq!(client.get_admin_signers(), vec![&env, admin.clone()]);
+    assert_eq!(
+        client.get_pending_contract_admin().unwrap().new_admin,
+        new_admin
+    );
+
+    client.accept_contract_admin();
+    let signers: std::vec::Vec<Address> = env.auths().into_iter().map(|(addr, _)| addr).collect();
+    assert_eq!(signers.len(), 1);
+    assert_eq!(signers[0], new_admin);
+    let (topics, _) = last_event(&env, &contract_id);
+    assert_eq!(
+        topics,
+        (symbol_short!("contract"), symbol_short!("admin")).into_val(&env)
+    );
+    assert_eq!(client.get_admin_signers(), vec![&env, new_admin]);
+    assert_eq!(client.get_pending_contract_admin(), None);
+    assert!(client.try_accept_contract_admin().is_err());
+}
+
+#[test]
+fn test_signer_set_change_drops_pending_handover() {
+    let (env, admin, _issuer, _contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let second = Address::generate(&env);
+    let third = Address::generate(&env);
+    client.propose_contract_admin(&Address::generate(&env));
+    run_admin_action(&client, &admin, AdminAction::AddSigner(second.clone()));
+    assert_eq!(client.get_pending_contract_admin(), None);
+    assert!(client.try_accept_contract_admin().is_err());
+
+    run_admin_action(&client, &admin, AdminAction::AddSigner(third.clone()));
+    run_admin_action(
+        &client,
+        &admin,
+        AdminAction::ProposeContractAdmin(Address::generate(&env)),
+    );
+    assert!(client.get_pending_contract_admin().is_some());
+    let id = client.propose_admin_action(&admin, &AdminAction::SetThreshold(2));
+    client.approve_admin_action(&second, &id);
+    client.execute_admin_action(&id);
+    assert_eq!(client.get_admin_threshold(), 2);
+    assert_eq!(client.get_pending_contract_admin(), None);
+    assert!(client.try_accept_contract_admin().is_err());
+    assert_eq!(client.get_admin_signers(), vec![&env, admin, second, third]);
+}
+
+#[test]
+fn test_contract_admin_handover_expires() {
+    let (env, admin, _issuer, _contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    client.propose_contract_admin(&Address::generate(&env));
+    let expires_at = client.get_pending_contract_admin().unwrap().expires_at;
+    assert_eq!(expires_at, 1_000 + 7 * 24 * 60 * 60);
+    env.ledger().with_mut(|li| li.timestamp = expires_at + 1);
+    assert!(client.try_accept_contract_admin().is_err());
+    assert_eq!(client.get_admin_signers(), vec![&env, admin]);
 }
 
 #[test]
 fn test_fee_config_default() {
     let (_env, admin, _issuer, _contract_id, client) = setup();
-    client.initialize(&admin, &String::from_str(&_env, "did:acta:default"));
+    client.initialize(
+        &admin,
+        &String::from_str(&_env, "did:acta:default"),
+        &deploy_salt(&_env),
+    );
     let config = client.fee_config();
     assert!(!config.enabled);
     assert!(!config.configured);
     assert!(config.token_contract.is_none());
     assert!(config.fee_dest.is_none());
-    assert!(config.fee_amount.is_none());
 }
 
 #[test]
 fn test_set_fee_config() {
     let (env, admin, _issuer, _contract_id, client) = setup();
-    client.initialize(&admin, &String::from_str(&env, "did:acta:default"));
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
     let token = Address::generate(&env);
     let fee_dest = Address::generate(&env);
-    client.set_fee_config(&token, &fee_dest, &1_000_000_i128);
+    client.set_fee_config(&token, &fee_dest);
     let config = client.fee_config();
     assert!(config.configured);
     assert_eq!(config.token_contract, Some(token));
     assert_eq!(config.fee_dest, Some(fee_dest));
-    assert_eq!(config.fee_amount, Some(1_000_000));
 }
 
 #[test]
 fn test_set_fee_enabled() {
     let (env, admin, _issuer, _contract_id, client) = setup();
-    client.initialize(&admin, &String::from_str(&env, "did:acta:default"));
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
     client.set_fee_enabled(&true);
     assert!(client.fee_config().enabled);
     client.set_fee_enabled(&false);
@@ -89,7 +325,11 @@ fn test_set_fee_enabled() {
 #[test]
 fn test_set_and_get_fee_admin() {
     let (env, admin, _issuer, _contract_id, client) = setup();
-    client.initialize(&admin, &String::from_str(&env, "did:acta:default"));
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
     assert_eq!(client.get_fee_admin(), 0);
     client.set_fee_admin(&100_i128);
     assert_eq!(client.get_fee_admin(), 100);
@@ -98,7 +338,11 @@ fn test_set_and_get_fee_admin() {
 #[test]
 fn test_set_and_get_fee_standard() {
     let (env, admin, _issuer, _contract_id, client) = setup();
-    client.initialize(&admin, &String::from_str(&env, "did:acta:default"));
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
     assert_eq!(client.get_fee_standard(), 1_000_000);
     client.set_fee_standard(&2_000_000_i128);
     assert_eq!(client.get_fee_standard(), 2_000_000);
@@ -107,7 +351,11 @@ fn test_set_and_get_fee_standard() {
 #[test]
 fn test_set_and_get_fee_early() {
     let (env, admin, _issuer, _contract_id, client) = setup();
-    client.initialize(&admin, &String::from_str(&env, "did:acta:default"));
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
     assert_eq!(client.get_fee_early(), 400_000);
     client.set_fee_early(&500_000_i128);
     assert_eq!(client.get_fee_early(), 500_000);
@@ -116,16 +364,25 @@ fn test_set_and_get_fee_early() {
 #[test]
 fn test_set_and_get_fee_custom() {
     let (env, admin, issuer, _contract_id, client) = setup();
-    client.initialize(&admin, &String::from_str(&env, "did:acta:default"));
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    assert_eq!(client.get_fee_custom(&issuer), None);
     client.set_fee_custom(&issuer, &300_000_i128);
-    assert_eq!(client.get_fee_custom(&issuer), 300_000);
+    assert_eq!(client.get_fee_custom(&issuer), Some(300_000));
 }
 
 #[test]
 #[should_panic]
 fn test_create_vault_twice_panics() {
     let (env, admin, _issuer, _contract_id, client) = setup();
-    client.initialize(&admin, &String::from_str(&env, "did:acta:default"));
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
     let owner = Address::generate(&env);
     let did_uri = String::from_str(&env, "did:pkh:stellar:testnet:OWNER");
     client.create_vault(&owner, &did_uri);
@@ -133,44 +390,415 @@ fn test_create_vault_twice_panics() {
 }
 
 #[test]
-fn test_set_vault_admin() {
-    let (env, admin, _issuer, _contract_id, client) = setup();
-    client.initialize(&admin, &String::from_str(&env, "did:acta:default"));
+fn test_vault_admin_handover() {
+    let (env, admin, _issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
     let owner = Address::generate(&env);
     client.create_vault(&owner, &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"));
     let new_admin = Address::generate(&env);
-    client.set_vault_admin(&owner, &new_admin);
+    client.propose_vault_admin(&owner, &new_admin);
+    let (topics, _) = last_event(&env, &contract_id);
+    assert_eq!(
+        topics,
+        (
+            symbol_short!("vault"),
+            symbol_short!("adm_prop"),
+            owner.clone()
+        )
+            .into_val(&env)
+    );
+    assert_eq!(
+        client.get_pending_vault_admin(&owner).unwrap().new_admin,
+        new_admin
+    );
+
+    client.accept_vault_admin(&owner);
+    let signers: std::vec::Vec<Address> = env.auths().into_iter().map(|(addr, _)| addr).collect();
+    assert_eq!(signers.len(), 1);
+    assert_eq!(signers[0], new_admin);
+    assert_eq!(client.get_pending_vault_admin(&owner), None);
+
     let issuer = Address::generate(&env);
-    client.authorize_issuer(&owner, &issuer);
+    client.authorize_issuer(&owner, &issuer, &None, &None, &vec![&env]);
+    let signers: std::vec::Vec<Address> = env.auths().into_iter().map(|(addr, _)| addr).collect();
+    assert_eq!(signers, [new_admin]);
+}
+
+#[test]
+fn test_vault_admin_handover_expires() {
+    let (env, admin, _issuer, _contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let owner = Address::generate(&env);
+    client.create_vault(
+        &owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"),
+    );
+    client.propose_vault_admin(&owner, &Address::generate(&env));
+    let expires_at = client.get_pending_vault_admin(&owner).unwrap().expires_at;
+    env.ledger().with_mut(|li| li.timestamp = expires_at + 1);
+    assert!(client.try_accept_vault_admin(&owner).is_err());
+
+    // A fresh proposal replaces the stale one.
+    let new_admin = Address::generate(&env);
+    client.propose_vault_admin(&owner, &new_admin);
+    client.accept_vault_admin(&owner);
+    assert_eq!(client.get_pending_vault_admin(&owner), None);
 }
 
 #[test]
 fn test_authorize_issuer() {
     let (env, admin, issuer, _contract_id, client) = setup();
-    client.initialize(&admin, &String::from_str(&env, "did:acta:default"));
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
     let owner = Address::generate(&env);
     client.create_vault(&owner, &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"));
-    client.authorize_issuer(&owner, &issuer);
+    client.authorize_issuer(&owner, &issuer, &None, &None, &vec![&env]);
 }
 
 #[test]
 fn test_authorize_issuers_bulk() {
     let (env, admin, issuer, _contract_id, client) = setup();
-    client.initialize(&admin, &String::from_str(&env, "did:acta:default"));
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
     let owner = Address::generate(&env);
     client.create_vault(&owner, &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"));
     let issuer2 = Address::generate(&env);
+    client.authorize_issuer(
+        &owner,
+        &issuer,
+        &None,
+        &Some(String::from_str(&env, "university")),
+        &vec![&env],
+    );
     let issuers = vec![&env, issuer.clone(), issuer2.clone()];
-    client.authorize_issuers(&owner, &issuers);
+    client.add_issuers(&owner, &issuers);
+
+    // Bulk add keeps the existing entry's metadata instead of replacing it.
+    let auth = client.get_issuer_authorization(&owner, &issuer).unwrap();
+    assert_eq!(auth.label, Some(String::from_str(&env, "university")));
+    assert!(client.get_issuer_authorization(&owner, &issuer2).is_some());
+    assert_eq!(client.list_issuers(&owner, &0, &10).issuers.len(), 2);
+}
+
+#[test]
+fn test_authorize_issuer_records_metadata() {
+    let (env, admin, issuer, _contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let owner = Address::generate(&env);
+    client.create_vault(
+        &owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"),
+    );
+    env.ledger().with_mut(|li| li.timestamp = 1_700_000_000);
+    let types = vec![&env, String::from_str(&env, "UniversityDegree")];
+    client.authorize_issuer(
+        &owner,
+        &issuer,
+        &None,
+        &Some(String::from_str(&env, "diploma")),
+        &types,
+    );
+
+    assert_eq!(
+        client.get_issuer_authorization(&owner, &issuer),
+        Some(IssuerAuthorization {
+            issuer: issuer.clone(),
+            authorized_at: 1_700_000_000,
+            expires_at: None,
+            label: Some(String::from_str(&env, "diploma")),
+            credential_types: types,
+        })
+    );
+    client.revoke_issuer(&owner, &issuer);
+    assert_eq!(client.get_issuer_authorization(&owner, &issuer), None);
+}
+
+#[test]
+fn test_issuer_authorization_expires() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let owner = Address::generate(&env);
+    client.create_vault(
+        &owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"),
+    );
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let expires_at = 1_000 + 3 * 86_400;
+    client.authorize_issuer(&owner, &issuer, &Some(expires_at), &None, &vec![&env]);
+    assert_eq!(client.get_issuer_expiry(&owner, &issuer), Some(expires_at));
+
+    let vc_data = String::from_str(&env, "<ciphertext>");
+    let issuer_did = String::from_str(&env, "did:pkh:stellar:testnet:ISSUER");
+    env.ledger().with_mut(|li| li.timestamp = expires_at);
+    client.issue(
+        &owner,
+        &String::from_str(&env, "vc-1"),
+        &vc_data,
+        &contract_id,
+        &issuer,
+        &issuer_did,
+        &None,
+        &None,
+        &true,
+    );
+
+    // A lapsed authorization can be granted again.
+    env.ledger().with_mut(|li| li.timestamp = expires_at + 1);
+    client.authorize_issuer(&owner, &issuer, &None, &None, &vec![&env]);
+    assert_eq!(client.get_issuer_expiry(&owner, &issuer), None);
+    client.issue(
+        &owner,
+        &String::from_str(&env, "vc-2"),
+        &vc_data,
+        &contract_id,
+        &issuer,
+        &issuer_did,
+        &None,
+        &None,
+        &true,
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_issue_after_authorization_expiry_panics() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let owner = Address::generate(&env);
+    client.create_vault(
+        &owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"),
+    );
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    client.authorize_issuer(&owner, &issuer, &Some(2_000), &None, &vec![&env]);
+    env.ledger().with_mut(|li| li.timestamp = 2_001);
+    let vc_data = String::from_str(&env, "<ciphertext>");
+    let issuer_did = String::from_str(&env, "did:pkh:stellar:testnet:ISSUER");
+    client.issue(
+        &owner,
+        &String::from_str(&env, "vc-1"),
+        &vc_data,
+        &contract_id,
+        &issuer,
+        &issuer_did,
+        &None,
+        &None,
+        &true,
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_authorize_issuer_with_past_expiry_panics() {
+    let (env, admin, issuer, _contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let owner = Address::generate(&env);
+    client.create_vault(
+        &owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"),
+    );
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    client.authorize_issuer(&owner, &issuer, &Some(999), &None, &vec![&env]);
+}
+
+#[test]
+fn test_list_issuers_paginates() {
+    let (env, admin, _issuer, _contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let owner = Address::generate(&env);
+    client.create_vault(
+        &owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"),
+    );
+    let mut issuers = vec![&env];
+    for _ in 0..70 {
+        issuers.push_back(Address::generate(&env));
+    }
+    client.add_issuers(&owner, &issuers);
+    client.revoke_issuer(&owner, &issuers.get_unchecked(5));
+
+    let mut seen = std::vec::Vec::new();
+    let mut cursor = Some(0);
+    while let Some(c) = cursor {
+        env.budget().reset_default();
+        let page = client.list_issuers(&owner, &c, &30);
+        assert!(page.issuers.len() <= 30);
+        seen.extend(page.issuers.iter().map(|auth| auth.issuer));
+        cursor = page.next_cursor;
+    }
+    assert_eq!(seen.len(), 69);
+    assert!(!seen.contains(&issuers.get_unchecked(5)));
+}
+
+#[test]
+fn test_migrate_vault_issuers() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let owner = Address::generate(&env);
+    client.create_vault(
+        &owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"),
+    );
+    let issuer2 = Address::generate(&env);
+    env.as_contract(&contract_id, || {
+        env.storage().persistent().set(
+            &DataKey::VaultIssuers(owner.clone()),
+            &vec![&env, issuer2.clone(), issuer.clone()],
+        );
+    });
+    assert!(client.get_issuer_authorization(&owner, &issuer).is_none());
+
+    client.migrate_vault_issuers(&owner);
+    let (topics, data) = last_event(&env, &contract_id);
+    assert_eq!(
+        topics,
+        (
+            symbol_short!("contract"),
+            symbol_short!("mig_iss"),
+            owner.clone()
+        )
+            .into_val(&env)
+    );
+    let count: u32 = data.into_val(&env);
+    assert_eq!(count, 2);
+    let page = client.list_issuers(&owner, &0, &10);
+    assert_eq!(page.issuers.get_unchecked(0).issuer, issuer);
+    assert_eq!(page.issuers.get_unchecked(1).issuer, issuer2);
+    env.as_contract(&contract_id, || {
+        assert!(storage::read_legacy_vault_issuers(&env, &owner).is_none());
+    });
+    client.issue(
+        &owner,
+        &String::from_str(&env, "vc-1"),
+        &String::from_str(&env, "<ciphertext>"),
+        &contract_id,
+        &issuer2,
+        &String::from_str(&env, "did:pkh:stellar:testnet:ISSUER"),
+        &None,
+        &None,
+        &true,
+    );
+}
+
+#[test]
+fn test_legacy_issuer_vector_authorizes_until_migrated() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let owner = Address::generate(&env);
+    client.create_vault(
+        &owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"),
+    );
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .persistent()
+            .set(&DataKey::VaultIssuers(owner.clone()), &vec![&env, issuer.clone()]);
+    });
+    client.issue(
+        &owner,
+        &String::from_str(&env, "vc-1"),
+        &String::from_str(&env, "<ciphertext>"),
+        &contract_id,
+        &issuer,
+        &String::from_str(&env, "did:pkh:stellar:testnet:ISSUER"),
+        &None,
+        &None,
+        &true,
+    );
+
+    // The first issuer change converts the vector before applying.
+    let issuer2 = Address::generate(&env);
+    client.add_issuers(&owner, &vec![&env, issuer2.clone()]);
+    env.as_contract(&contract_id, || {
+        assert!(storage::read_legacy_vault_issuers(&env, &owner).is_none());
+    });
+    let page = client.list_issuers(&owner, &0, &10);
+    assert_eq!(page.issuers.len(), 2);
+    assert_eq!(page.issuers.get_unchecked(0).issuer, issuer);
+    assert_eq!(page.issuers.get_unchecked(1).issuer, issuer2);
+}
+
+#[test]
+fn test_add_issuers_renews_lapsed_authorization() {
+    let (env, admin, issuer, _contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let owner = Address::generate(&env);
+    client.create_vault(
+        &owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"),
+    );
+    let current = Address::generate(&env);
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    client.authorize_issuer(&owner, &issuer, &Some(2_000), &None, &vec![&env]);
+    client.authorize_issuer(&owner, &current, &Some(5_000), &None, &vec![&env]);
+
+    env.ledger().with_mut(|li| li.timestamp = 3_000);
+    client.add_issuers(&owner, &vec![&env, issuer.clone(), current.clone()]);
+    assert_eq!(client.get_issuer_expiry(&owner, &issuer), None);
+    assert_eq!(
+        client.get_issuer_authorization(&owner, &issuer).unwrap().authorized_at,
+        3_000
+    );
+    assert_eq!(client.get_issuer_expiry(&owner, &current), Some(5_000));
 }
 
 #[test]
 fn test_revoke_issuer() {
     let (env, admin, issuer, _contract_id, client) = setup();
-    client.initialize(&admin, &String::from_str(&env, "did:acta:default"));
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
     let owner = Address::generate(&env);
     client.create_vault(&owner, &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"));
-    client.authorize_issuer(&owner, &issuer);
+    client.authorize_issuer(&owner, &issuer, &None, &None, &vec![&env]);
     client.revoke_issuer(&owner, &issuer);
 }
 
@@ -178,21 +806,39 @@ fn test_revoke_issuer() {
 #[should_panic]
 fn test_issue_after_revoke_issuer_panics() {
     let (env, admin, issuer, contract_id, client) = setup();
-    client.initialize(&admin, &String::from_str(&env, "did:acta:default"));
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
     let owner = Address::generate(&env);
     client.create_vault(&owner, &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"));
-    client.authorize_issuer(&owner, &issuer);
+    client.authorize_issuer(&owner, &issuer, &None, &None, &vec![&env]);
     client.revoke_issuer(&owner, &issuer);
     let vc_id = String::from_str(&env, "vc-1");
     let vc_data = String::from_str(&env, "<ciphertext>");
     let issuer_did = String::from_str(&env, "did:pkh:stellar:testnet:ISSUER");
-    client.issue(&owner, &vc_id, &vc_data, &contract_id, &issuer, &issuer_did, &0_i128);
+    client.issue(
+        &owner,
+        &vc_id,
+        &vc_data,
+        &contract_id,
+        &issuer,
+        &issuer_did,
+        &None,
+        &None,
+        &true,
+    );
 }
 
 #[test]
 fn test_revoke_vault() {
     let (env, admin, _issuer, _contract_id, client) = setup();
-    client.initialize(&admin, &String::from_str(&env, "did:acta:default"));
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
     let owner = Address::generate(&env);
     client.create_vault(&owner, &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"));
     client.revoke_vault(&owner);
@@ -202,21 +848,39 @@ fn test_revoke_vault() {
 #[should_panic]
 fn test_issue_after_revoke_vault_panics() {
     let (env, admin, issuer, contract_id, client) = setup();
-    client.initialize(&admin, &String::from_str(&env, "did:acta:default"));
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
     let owner = Address::generate(&env);
     client.create_vault(&owner, &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"));
-    client.authorize_issuer(&owner, &issuer);
+    client.authorize_issuer(&owner, &issuer, &None, &None, &vec![&env]);
     client.revoke_vault(&owner);
     let vc_id = String::from_str(&env, "vc-1");
     let vc_data = String::from_str(&env, "<ciphertext>");
     let issuer_did = String::from_str(&env, "did:pkh:stellar:testnet:ISSUER");
-    client.issue(&owner, &vc_id, &vc_data, &contract_id, &issuer, &issuer_did, &0_i128);
+    client.issue(
+        &owner,
+        &vc_id,
+        &vc_data,
+        &contract_id,
+        &issuer,
+        &issuer_did,
+        &None,
+        &None,
+        &true,
+    );
 }
 
 #[test]
 fn test_list_vc_ids_empty() {
     let (env, admin, _issuer, _contract_id, client) = setup();
-    client.initialize(&admin, &String::from_str(&env, "did:acta:default"));
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
     let owner = Address::generate(&env);
     client.create_vault(&owner, &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"));
     assert_eq!(client.list_vc_ids(&owner).len(), 0);
@@ -225,7 +889,11 @@ fn test_list_vc_ids_empty() {
 #[test]
 fn test_get_vc_none_for_missing() {
     let (env, admin, _issuer, _contract_id, client) = setup();
-    client.initialize(&admin, &String::from_str(&env, "did:acta:default"));
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
     let owner = Address::generate(&env);
     client.create_vault(&owner, &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"));
     let vc_id = String::from_str(&env, "nonexistent");
@@ -235,7 +903,11 @@ fn test_get_vc_none_for_missing() {
 #[test]
 fn test_verify_vc_invalid_when_not_in_vault() {
     let (env, admin, _issuer, _contract_id, client) = setup();
-    client.initialize(&admin, &String::from_str(&env, "did:acta:default"));
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
     let owner = Address::generate(&env);
     client.create_vault(&owner, &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"));
     let vc_id = String::from_str(&env, "nonexistent");
@@ -246,14 +918,28 @@ fn test_verify_vc_invalid_when_not_in_vault() {
 #[test]
 fn test_vault_authorize_and_store_and_list_and_get() {
     let (env, admin, issuer, contract_id, client) = setup();
-    client.initialize(&admin, &String::from_str(&env, "did:acta:default"));
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
     let owner = Address::generate(&env);
     client.create_vault(&owner, &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"));
-    client.authorize_issuer(&owner, &issuer);
+    client.authorize_issuer(&owner, &issuer, &None, &None, &vec![&env]);
     let vc_id = String::from_str(&env, "vc-1");
     let vc_data = String::from_str(&env, "<ciphertext>");
     let issuer_did = String::from_str(&env, "did:pkh:stellar:testnet:ISSUER");
-    client.issue(&owner, &vc_id, &vc_data, &contract_id, &issuer, &issuer_did, &0_i128);
+    client.issue(
+        &owner,
+        &vc_id,
+        &vc_data,
+        &contract_id,
+        &issuer,
+        &issuer_did,
+        &None,
+        &None,
+        &true,
+    );
     assert_eq!(client.list_vc_ids(&owner).len(), 1);
     assert_eq!(client.get_vc(&owner, &vc_id).unwrap().data, vc_data);
 }
@@ -261,33 +947,79 @@ fn test_vault_authorize_and_store_and_list_and_get() {
 #[test]
 fn test_issue_verify_revoke_flow_local_vault() {
     let (env, admin, issuer, contract_id, client) = setup();
-    client.initialize(&admin, &String::from_str(&env, "did:acta:default"));
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
     let owner = Address::generate(&env);
     client.create_vault(&owner, &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"));
-    client.authorize_issuer(&owner, &issuer);
+    client.authorize_issuer(&owner, &issuer, &None, &None, &vec![&env]);
     let vc_id = String::from_str(&env, "vc-123");
     let vc_data = String::from_str(&env, "<ciphertext>");
     let issuer_did = String::from_str(&env, "did:pkh:stellar:testnet:ISSUER");
-    client.issue(&owner, &vc_id, &vc_data, &contract_id, &issuer, &issuer_did, &0_i128);
-    assert_eq!(client.verify_vc(&owner, &vc_id).get(String::from_str(&env, "status")).unwrap(), String::from_str(&env, "valid"));
-    client.revoke(&vc_id, &String::from_str(&env, "2025-12-18T00:00:00Z"));
-    assert_eq!(client.verify_vc(&owner, &vc_id).get(String::from_str(&env, "status")).unwrap(), String::from_str(&env, "revoked"));
+    client.issue(
+        &owner,
+        &vc_id,
+        &vc_data,
+        &contract_id,
+        &issuer,
+        &issuer_did,
+        &None,
+        &None,
+        &true,
+    );
+    assert_eq!(
+        client
+            .verify_vc(&owner, &vc_id)
+            .get(String::from_str(&env, "status"))
+            .unwrap(),
+        String::from_str(&env, "valid")
+    );
+    client.revoke(
+        &issuer,
+        &issuer,
+        &vc_id,
+        &RevocationReason::Unspecified,
+        &None,
+    );
+    assert_eq!(
+        client
+            .verify_vc(&owner, &vc_id)
+            .get(String::from_str(&env, "status"))
+            .unwrap(),
+        String::from_str(&env, "revoked")
+    );
 }
 
 #[test]
 fn test_push_moves_between_vaults() {
     let (env, admin, issuer, contract_id, client) = setup();
-    client.initialize(&admin, &String::from_str(&env, "did:acta:default"));
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
     let from_owner = Address::generate(&env);
     let to_owner = Address::generate(&env);
     client.create_vault(&from_owner, &String::from_str(&env, "did:pkh:stellar:testnet:FROM"));
     client.create_vault(&to_owner, &String::from_str(&env, "did:pkh:stellar:testnet:TO"));
-    client.authorize_issuer(&from_owner, &issuer);
+    client.authorize_issuer(&from_owner, &issuer, &None, &None, &vec![&env]);
     let vc_id = String::from_str(&env, "vc-push");
     let vc_data = String::from_str(&env, "<ciphertext>");
     let issuer_did = String::from_str(&env, "did:pkh:stellar:testnet:ISSUER");
-    client.issue(&from_owner, &vc_id, &vc_data, &contract_id, &issuer, &issuer_did, &0_i128);
-    client.push(&from_owner, &to_owner, &vc_id, &issuer);
+    client.issue(
+        &from_owner,
+        &vc_id,
+        &vc_data,
+        &contract_id,
+        &issuer,
+        &issuer_did,
+        &None,
+        &None,
+        &true,
+    );
+    client.push(&from_owner, &to_owner, &vc_id);
     assert!(client.get_vc(&from_owner, &vc_id).is_none());
     assert!(client.get_vc(&to_owner, &vc_id).is_some());
 }
@@ -295,75 +1027,107 @@ fn test_push_moves_between_vaults() {
 #[test]
 fn test_issue_returns_vc_id() {
     let (env, admin, issuer, contract_id, client) = setup();
-    client.initialize(&admin, &String::from_str(&env, "did:acta:default"));
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
     let owner = Address::generate(&env);
     client.create_vault(&owner, &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"));
-    client.authorize_issuer(&owner, &issuer);
+    client.authorize_issuer(&owner, &issuer, &None, &None, &vec![&env]);
     let vc_id = String::from_str(&env, "vc-return");
     let vc_data = String::from_str(&env, "<ciphertext>");
     let issuer_did = String::from_str(&env, "did:pkh:stellar:testnet:ISSUER");
-    let returned = client.issue(&owner, &vc_id, &vc_data, &contract_id, &issuer, &issuer_did, &0_i128);
+    let returned = client.issue(
+        &owner,
+        &vc_id,
+        &vc_data,
+        &contract_id,
+        &issuer,
+        &issuer_did,
+        &None,
+        &None,
+        &true,
+    );
     assert_eq!(returned, vc_id);
 }
 
-#[test]
-fn test_issue_with_fee_override() {
-    let (env, admin, issuer, contract_id, client) = setup();
-    client.initialize(&admin, &String::from_str(&env, "did:acta:default"));
-    let owner = Address::generate(&env);
-    client.create_vault(&owner, &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"));
-    client.authorize_issuer(&owner, &issuer);
-    let vc_id = String::from_str(&env, "vc-fee");
-    let vc_data = String::from_str(&env, "<ciphertext>");
-    let issuer_did = String::from_str(&env, "did:pkh:stellar:testnet:ISSUER");
-    client.issue(&owner, &vc_id, &vc_data, &contract_id, &issuer, &issuer_did, &0_i128);
-    assert!(client.get_vc(&owner, &vc_id).is_some());
-}
-
 #[test]
 #[should_panic]
 fn test_issue_invalid_vault_contract_panics() {
     let (env, admin, issuer, _contract_id, client) = setup();
-    client.initialize(&admin, &String::from_str(&env, "did:acta:default"));
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
     let owner = Address::generate(&env);
     client.create_vault(&owner, &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"));
-    client.authorize_issuer(&owner, &issuer);
+    client.authorize_issuer(&owner, &issuer, &None, &None, &vec![&env]);
     let wrong_contract = Address::generate(&env);
     let vc_id = String::from_str(&env, "vc-1");
     let vc_data = String::from_str(&env, "<ciphertext>");
     let issuer_did = String::from_str(&env, "did:pkh:stellar:testnet:ISSUER");
-    client.issue(&owner, &vc_id, &vc_data, &wrong_contract, &issuer, &issuer_did, &0_i128);
+    client.issue(
+        &owner,
+        &vc_id,
+        &vc_data,
+        &wrong_contract,
+        &issuer,
+        &issuer_did,
+        &None,
+        &None,
+        &true,
+    );
 }
 
 #[test]
 #[should_panic]
 fn test_revoke_nonexistent_vc_panics() {
-    let (env, admin, _issuer, _contract_id, client) = setup();
-    client.initialize(&admin, &String::from_str(&env, "did:acta:default"));
+    let (env, admin, issuer, _contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
     let vc_id = String::from_str(&env, "nonexistent");
-    let date = String::from_str(&env, "2025-12-18T00:00:00Z");
-    client.revoke(&vc_id, &date);
+    let note = Some(String::from_str(&env, "superseded"));
+    client.revoke(
+        &issuer,
+        &issuer,
+        &vc_id,
+        &RevocationReason::Superseded,
+        &note,
+    );
 }
 
 #[test]
 #[should_panic]
 fn test_push_nonexistent_vc_panics() {
     let (env, admin, issuer, _contract_id, client) = setup();
-    client.initialize(&admin, &String::from_str(&env, "did:acta:default"));
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
     let from_owner = Address::generate(&env);
     let to_owner = Address::generate(&env);
     client.create_vault(&from_owner, &String::from_str(&env, "did:pkh:stellar:testnet:FROM"));
     client.create_vault(&to_owner, &String::from_str(&env, "did:pkh:stellar:testnet:TO"));
-    client.authorize_issuer(&from_owner, &issuer);
+    client.authorize_issuer(&from_owner, &issuer, &None, &None, &vec![&env]);
     let vc_id = String::from_str(&env, "nonexistent");
-    client.push(&from_owner, &to_owner, &vc_id, &issuer);
+    client.push(&from_owner, &to_owner, &vc_id);
 }
 
 #[test]
 #[should_panic]
 fn test_migrate_none_without_legacy_panics() {
     let (env, admin, _issuer, _contract_id, client) = setup();
-    client.initialize(&admin, &String::from_str(&env, "did:acta:default"));
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
     client.migrate(&None);
 }
 
@@ -371,8 +1135,2974 @@ fn test_migrate_none_without_legacy_panics() {
 #[should_panic]
 fn test_migrate_some_without_legacy_vault_panics() {
     let (env, admin, _issuer, _contract_id, client) = setup();
-    client.initialize(&admin, &String::from_str(&env, "did:acta:default"));
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
     let owner = Address::generate(&env);
     client.create_vault(&owner, &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"));
     client.migrate(&Some(owner));
 }
+
+#[test]
+fn test_initialize_emits_event() {
+    let (env, admin, _issuer, contract_id, client) = setup();
+    let default_did = String::from_str(&env, "did:acta:default");
+    client.initialize(&admin, &default_did, &deploy_salt(&env));
+    let (topics, data) = last_event(&env, &contract_id);
+    assert_eq!(
+        topics,
+        (symbol_short!("contract"), symbol_short!("init")).into_val(&env)
+    );
+    let data: (Address, String) = data.into_val(&env);
+    assert_eq!(data, (admin, default_did));
+}
+
+#[test]
+fn test_fee_setters_emit_events() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    client.set_fee_enabled(&true);
+    let (topics, data) = last_event(&env, &contract_id);
+    assert_eq!(
+        topics,
+        (symbol_short!("fee"), symbol_short!("enabled")).into_val(&env)
+    );
+    let enabled: bool = data.into_val(&env);
+    assert!(enabled);
+    client.set_fee_early(&500_000_i128);
+    let (topics, data) = last_event(&env, &contract_id);
+    assert_eq!(
+        topics,
+        (symbol_short!("fee"), symbol_short!("early")).into_val(&env)
+    );
+    let amount: i128 = data.into_val(&env);
+    assert_eq!(amount, 500_000);
+    client.set_fee_custom(&issuer, &300_000_i128);
+    let (topics, _data) = last_event(&env, &contract_id);
+    assert_eq!(
+        topics,
+        (symbol_short!("fee"), symbol_short!("custom"), issuer).into_val(&env)
+    );
+}
+
+#[test]
+fn test_vault_lifecycle_emits_events() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let owner = Address::generate(&env);
+    let did_uri = String::from_str(&env, "did:pkh:stellar:testnet:OWNER");
+    client.create_vault(&owner, &did_uri);
+    let (topics, data) = last_event(&env, &contract_id);
+    assert_eq!(
+        topics,
+        (
+            symbol_short!("vault"),
+            symbol_short!("created"),
+            owner.clone()
+        )
+            .into_val(&env)
+    );
+    let data: String = data.into_val(&env);
+    assert_eq!(data, did_uri);
+
+    client.authorize_issuer(&owner, &issuer, &None, &None, &vec![&env]);
+    let (topics, data) = last_event(&env, &contract_id);
+    assert_eq!(
+        topics,
+        (
+            symbol_short!("vault"),
+            symbol_short!("iss_auth"),
+            owner.clone()
+        )
+            .into_val(&env)
+    );
+    let data: Address = data.into_val(&env);
+    assert_eq!(data, issuer);
+
+    client.revoke_issuer(&owner, &issuer);
+    let (topics, _data) = last_event(&env, &contract_id);
+    assert_eq!(
+        topics,
+        (
+            symbol_short!("vault"),
+            symbol_short!("iss_rev"),
+            owner.clone()
+        )
+            .into_val(&env)
+    );
+
+    client.revoke_vault(&owner);
+    let (topics, _data) = last_event(&env, &contract_id);
+    assert_eq!(
+        topics,
+        (symbol_short!("vault"), symbol_short!("revoked"), owner).into_val(&env)
+    );
+}
+
+#[test]
+fn test_vc_lifecycle_emits_events() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let from_owner = Address::generate(&env);
+    let to_owner = Address::generate(&env);
+    client.create_vault(
+        &from_owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:FROM"),
+    );
+    client.create_vault(
+        &to_owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:TO"),
+    );
+    client.authorize_issuer(&from_owner, &issuer, &None, &None, &vec![&env]);
+    let vc_id = String::from_str(&env, "vc-events");
+    let vc_data = String::from_str(&env, "<ciphertext>");
+    let issuer_did = String::from_str(&env, "did:pkh:stellar:testnet:ISSUER");
+    client.issue(
+        &from_owner,
+        &vc_id,
+        &vc_data,
+        &contract_id,
+        &issuer,
+        &issuer_did,
+        &None,
+        &None,
+        &true,
+    );
+    let (topics, data) = last_event(&env, &contract_id);
+    assert_eq!(
+        topics,
+        (
+            symbol_short!("vc"),
+            symbol_short!("issued"),
+            from_owner.clone()
+        )
+            .into_val(&env)
+    );
+    let data: (String, Address) = data.into_val(&env);
+    assert_eq!(data, (vc_id.clone(), issuer.clone()));
+
+    client.push(&from_owner, &to_owner, &vc_id);
+    let (topics, data) = last_event(&env, &contract_id);
+    assert_eq!(
+        topics,
+        (symbol_short!("vc"), symbol_short!("pushed"), from_owner).into_val(&env)
+    );
+    let data: (Address, String) = data.into_val(&env);
+    assert_eq!(data, (to_owner, vc_id.clone()));
+
+    let note = Some(String::from_str(&env, "superseded"));
+    client.revoke(
+        &issuer,
+        &issuer,
+        &vc_id,
+        &RevocationReason::Superseded,
+        &note,
+    );
+    let (topics, data) = last_event(&env, &contract_id);
+    assert_eq!(
+        topics,
+        (symbol_short!("vc"), symbol_short!("revoked"), issuer).into_val(&env)
+    );
+    let data: (String, StatusChange, RevocationReason) = data.into_val(&env);
+    assert_eq!(data.0, vc_id);
+    assert_eq!(data.1.note, note);
+    assert_eq!(data.2, RevocationReason::Superseded);
+}
+
+#[test]
+fn test_same_vc_id_from_two_issuers_is_independent() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let issuer2 = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let owner2 = Address::generate(&env);
+    client.create_vault(
+        &owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"),
+    );
+    client.create_vault(
+        &owner2,
+        &String::from_str(&env, "did:pkh:stellar:testnet:OWNER2"),
+    );
+    client.authorize_issuer(&owner, &issuer, &None, &None, &vec![&env]);
+    client.authorize_issuer(&owner2, &issuer2, &None, &None, &vec![&env]);
+    let vc_id = String::from_str(&env, "vc-shared");
+    let vc_data = String::from_str(&env, "<ciphertext>");
+    let issuer_did = String::from_str(&env, "did:pkh:stellar:testnet:ISSUER");
+    client.issue(
+        &owner,
+        &vc_id,
+        &vc_data,
+        &contract_id,
+        &issuer,
+        &issuer_did,
+        &None,
+        &None,
+        &true,
+    );
+    client.issue(
+        &owner2,
+        &vc_id,
+        &vc_data,
+        &contract_id,
+        &issuer2,
+        &issuer_did,
+        &None,
+        &None,
+        &true,
+    );
+    client.revoke(
+        &issuer,
+        &issuer,
+        &vc_id,
+        &RevocationReason::Unspecified,
+        &None,
+    );
+    let status_k = String::from_str(&env, "status");
+    assert_eq!(
+        client
+            .verify_vc(&owner, &vc_id)
+            .get(status_k.clone())
+            .unwrap(),
+        String::from_str(&env, "revoked")
+    );
+    assert_eq!(
+        client.verify_vc(&owner2, &vc_id).get(status_k).unwrap(),
+        String::from_str(&env, "valid")
+    );
+    assert_eq!(client.get_vc(&owner2, &vc_id).unwrap().issuer, issuer2);
+}
+
+#[test]
+#[should_panic]
+fn test_reissue_revoked_vc_id_panics() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let owner = Address::generate(&env);
+    let owner2 = Address::generate(&env);
+    client.create_vault(
+        &owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"),
+    );
+    client.create_vault(
+        &owner2,
+        &String::from_str(&env, "did:pkh:stellar:testnet:OWNER2"),
+    );
+    client.authorize_issuer(&owner, &issuer, &None, &None, &vec![&env]);
+    client.authorize_issuer(&owner2, &issuer, &None, &None, &vec![&env]);
+    let vc_id = String::from_str(&env, "vc-1");
+    let vc_data = String::from_str(&env, "<ciphertext>");
+    let issuer_did = String::from_str(&env, "did:pkh:stellar:testnet:ISSUER");
+    client.issue(
+        &owner,
+        &vc_id,
+        &vc_data,
+        &contract_id,
+        &issuer,
+        &issuer_did,
+        &None,
+        &None,
+        &true,
+    );
+    client.revoke(
+        &issuer,
+        &issuer,
+        &vc_id,
+        &RevocationReason::Unspecified,
+        &None,
+    );
+    client.issue(
+        &owner2,
+        &vc_id,
+        &vc_data,
+        &contract_id,
+        &issuer,
+        &issuer_did,
+        &None,
+        &None,
+        &true,
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_issue_id_already_in_vault_panics() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let issuer2 = Address::generate(&env);
+    let owner = Address::generate(&env);
+    client.create_vault(
+        &owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"),
+    );
+    client.add_issuers(&owner, &vec![&env, issuer.clone(), issuer2.clone()]);
+    let vc_id = String::from_str(&env, "vc-1");
+    let vc_data = String::from_str(&env, "<ciphertext>");
+    let issuer_did = String::from_str(&env, "did:pkh:stellar:testnet:ISSUER");
+    client.issue(
+        &owner,
+        &vc_id,
+        &vc_data,
+        &contract_id,
+        &issuer,
+        &issuer_did,
+        &None,
+        &None,
+        &true,
+    );
+    client.issue(
+        &owner,
+        &vc_id,
+        &vc_data,
+        &contract_id,
+        &issuer2,
+        &issuer_did,
+        &None,
+        &None,
+        &true,
+    );
+}
+
+#[test]
+fn test_migrate_registry_moves_legacy_entries() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let owner = Address::generate(&env);
+    client.create_vault(
+        &owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"),
+    );
+    let vc_id = String::from_str(&env, "vc-legacy");
+    let date = String::from_str(&env, "2025-12-18T00:00:00Z");
+    env.as_contract(&contract_id, || {
+        let legacy_vc = LegacyVerifiableCredential {
+            id: vc_id.clone(),
+            data: String::from_str(&env, "<ciphertext>"),
+            issuance_contract: contract_id.clone(),
+            issuer_did: String::from_str(&env, "did:pkh:stellar:testnet:ISSUER"),
+        };
+        let persistent = env.storage().persistent();
+        persistent.set(&DataKey::VaultVC(owner.clone(), vc_id.clone()), &legacy_vc);
+        persistent.set(
+            &DataKey::VCStatus(vc_id.clone()),
+            &LegacyVCStatus::Revoked(date.clone()),
+        );
+        persistent.set(&DataKey::VCOwner(vc_id.clone()), &owner);
+    });
+
+    client.migrate_registry(&issuer, &owner, &vec![&env, vc_id.clone()]);
+
+    assert_eq!(client.get_vc(&owner, &vc_id).unwrap().issuer, issuer);
+    let m = client.verify_vc(&owner, &vc_id);
+    assert_eq!(
+        m.get(String::from_str(&env, "status")).unwrap(),
+        String::from_str(&env, "revoked")
+    );
+    assert_eq!(
+        m.get(String::from_str(&env, "since")).unwrap(),
+        String::from_str(&env, "0")
+    );
+    assert_eq!(m.get(String::from_str(&env, "note")).unwrap(), date);
+    assert_eq!(
+        m.get(String::from_str(&env, "reason")).unwrap(),
+        String::from_str(&env, "unspecified")
+    );
+    env.as_contract(&contract_id, || {
+        assert!(storage::read_legacy_vc_status(&env, &vc_id).is_none());
+        assert_eq!(
+            storage::read_vc_owner(&env, &issuer, &vc_id),
+            Some(owner.clone())
+        );
+    });
+}
+
+#[test]
+fn test_legacy_status_is_read_until_migrated() {
+    let (env, admin, _issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let owner = Address::generate(&env);
+    client.create_vault(
+        &owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"),
+    );
+    let vc_id = String::from_str(&env, "vc-legacy");
+    let date = String::from_str(&env, "2025-12-18T00:00:00Z");
+    env.as_contract(&contract_id, || {
+        let legacy_vc = LegacyVerifiableCredential {
+            id: vc_id.clone(),
+            data: String::from_str(&env, "<ciphertext>"),
+            issuance_contract: contract_id.clone(),
+            issuer_did: String::from_str(&env, "did:pkh:stellar:testnet:ISSUER"),
+        };
+        let persistent = env.storage().persistent();
+        persistent.set(&DataKey::VaultVC(owner.clone(), vc_id.clone()), &legacy_vc);
+        persistent.set(
+            &DataKey::VCStatus(vc_id.clone()),
+            &LegacyVCStatus::Revoked(date.clone()),
+        );
+    });
+
+    let m = client.verify_vc(&owner, &vc_id);
+    assert_eq!(
+        m.get(String::from_str(&env, "status")).unwrap(),
+        String::from_str(&env, "revoked")
+    );
+    assert_eq!(m.get(String::from_str(&env, "note")).unwrap(), date);
+    // Only this contract's scope sees the legacy entry.
+    env.as_contract(&contract_id, || {
+        assert!(
+            storage::read_vc_status(&env, &Address::generate(&env), &vc_id) == VCStatus::Invalid
+        );
+    });
+}
+
+#[test]
+fn test_migrate_registry_rejects_other_legacy_owner() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let owner = Address::generate(&env);
+    client.create_vault(
+        &owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"),
+    );
+    let vc_id = String::from_str(&env, "vc-legacy");
+    env.as_contract(&contract_id, || {
+        let persistent = env.storage().persistent();
+        persistent.set(&DataKey::VCStatus(vc_id.clone()), &LegacyVCStatus::Valid);
+        persistent.set(&DataKey::VCOwner(vc_id.clone()), &Address::generate(&env));
+    });
+
+    assert_eq!(
+        client
+            .try_migrate_registry(&issuer, &owner, &vec![&env, vc_id.clone()])
+            .err(),
+        Some(Ok(ContractError::LegacyOwnerMismatch.into()))
+    );
+}
+
+#[test]
+fn test_legacy_payload_without_registry_entry_is_readable() {
+    let (env, admin, _issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let owner = Address::generate(&env);
+    client.create_vault(
+        &owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"),
+    );
+    let external = Address::generate(&env);
+    let vc_id = String::from_str(&env, "vc-external");
+    env.as_contract(&contract_id, || {
+        let legacy_vc = LegacyVerifiableCredential {
+            id: vc_id.clone(),
+            data: String::from_str(&env, "<ciphertext>"),
+            issuance_contract: external.clone(),
+            issuer_did: String::from_str(&env, "did:pkh:stellar:testnet:ISSUER"),
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::VaultVC(owner.clone(), vc_id.clone()), &legacy_vc);
+        storage::append_vault_vc_id(&env, &owner, &vc_id);
+    });
+
+    let vc = client.get_vc(&owner, &vc_id).unwrap();
+    assert_eq!(vc.issuer, external);
+    assert!(vc.transferable);
+    assert_eq!(client.list_vcs(&owner, &0, &10, &no_filter()).vcs.len(), 1);
+    client.delete_vc(&owner, &vc_id, &false);
+    assert!(client.get_vc(&owner, &vc_id).is_none());
+}
+
+#[test]
+#[should_panic]
+fn test_migrate_registry_twice_panics() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let owner = Address::generate(&env);
+    client.create_vault(
+        &owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"),
+    );
+    let vc_id = String::from_str(&env, "vc-legacy");
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .persistent()
+            .set(&DataKey::VCStatus(vc_id.clone()), &LegacyVCStatus::Valid);
+    });
+    client.migrate_registry(&issuer, &owner, &vec![&env, vc_id.clone()]);
+    client.migrate_registry(&issuer, &owner, &vec![&env, vc_id]);
+}
+
+#[test]
+fn test_issue_charges_standard_fee() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let (token, fee_dest) = setup_fee_token(&env, &client, &issuer, 5_000_000);
+    assert_eq!(client.get_issuer_tier(&issuer), FeeTier::Standard);
+    issue_one(&env, &client, &contract_id, &issuer, "vc-standard");
+    assert_eq!(token.balance(&fee_dest), 1_000_000);
+    assert_eq!(token.balance(&issuer), 4_000_000);
+}
+
+#[test]
+fn test_issue_charges_early_fee() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let (token, fee_dest) = setup_fee_token(&env, &client, &issuer, 5_000_000);
+    client.set_issuer_tier(&issuer, &FeeTier::Early);
+    assert_eq!(client.get_issuer_fee(&issuer), 400_000);
+    issue_one(&env, &client, &contract_id, &issuer, "vc-early");
+    assert_eq!(token.balance(&fee_dest), 400_000);
+}
+
+#[test]
+fn test_issue_custom_fee_overrides_tier() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let (token, fee_dest) = setup_fee_token(&env, &client, &issuer, 5_000_000);
+    client.set_issuer_tier(&issuer, &FeeTier::Early);
+    client.set_fee_custom(&issuer, &250_000_i128);
+    issue_one(&env, &client, &contract_id, &issuer, "vc-custom");
+    assert_eq!(token.balance(&fee_dest), 250_000);
+}
+
+#[test]
+fn test_issue_by_contract_admin_charges_admin_fee() {
+    let (env, admin, _issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let (token, fee_dest) = setup_fee_token(&env, &client, &admin, 5_000_000);
+    client.set_fee_admin(&10_i128);
+    issue_one(&env, &client, &contract_id, &admin, "vc-admin");
+    assert_eq!(token.balance(&fee_dest), 10);
+    assert_eq!(token.balance(&admin), 4_999_990);
+}
+
+#[test]
+fn test_issue_no_charge_when_fees_disabled() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let (token, fee_dest) = setup_fee_token(&env, &client, &issuer, 5_000_000);
+    client.set_fee_enabled(&false);
+    issue_one(&env, &client, &contract_id, &issuer, "vc-free");
+    assert_eq!(token.balance(&fee_dest), 0);
+    assert_eq!(token.balance(&issuer), 5_000_000);
+}
+
+#[test]
+#[should_panic]
+fn test_issue_fee_enabled_without_config_panics() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    client.set_fee_enabled(&true);
+    issue_one(&env, &client, &contract_id, &issuer, "vc-unconfigured");
+}
+
+#[test]
+fn test_verify_vc_respects_validity_window() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let owner = Address::generate(&env);
+    client.create_vault(
+        &owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"),
+    );
+    client.authorize_issuer(&owner, &issuer, &None, &None, &vec![&env]);
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let vc_id = String::from_str(&env, "vc-window");
+    let vc_data = String::from_str(&env, "<ciphertext>");
+    let issuer_did = String::from_str(&env, "did:pkh:stellar:testnet:ISSUER");
+    client.issue(
+        &owner,
+        &vc_id,
+        &vc_data,
+        &contract_id,
+        &issuer,
+        &issuer_did,
+        &Some(2_000_u64),
+        &Some(3_000_u64),
+        &true,
+    );
+    let vc = client.get_vc(&owner, &vc_id).unwrap();
+    assert_eq!(vc.valid_from, Some(2_000));
+    assert_eq!(vc.valid_until, Some(3_000));
+
+    let status_k = String::from_str(&env, "status");
+    let m = client.verify_vc(&owner, &vc_id);
+    assert_eq!(
+        m.get(status_k.clone()).unwrap(),
+        String::from_str(&env, "not_yet_valid")
+    );
+    assert_eq!(
+        m.get(String::from_str(&env, "valid_from")).unwrap(),
+        String::from_str(&env, "2000")
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = 3_000);
+    assert_eq!(
+        client
+            .verify_vc(&owner, &vc_id)
+            .get(status_k.clone())
+            .unwrap(),
+        String::from_str(&env, "valid")
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = 3_001);
+    let m = client.verify_vc(&owner, &vc_id);
+    assert_eq!(m.get(status_k).unwrap(), String::from_str(&env, "expired"));
+    assert_eq!(
+        m.get(String::from_str(&env, "since")).unwrap(),
+        String::from_str(&env, "3000")
+    );
+}
+
+#[test]
+fn test_revoked_takes_precedence_over_expiry() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let owner = Address::generate(&env);
+    client.create_vault(
+        &owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"),
+    );
+    client.authorize_issuer(&owner, &issuer, &None, &None, &vec![&env]);
+    let vc_id = String::from_str(&env, "vc-expiring");
+    let vc_data = String::from_str(&env, "<ciphertext>");
+    let issuer_did = String::from_str(&env, "did:pkh:stellar:testnet:ISSUER");
+    client.issue(
+        &owner,
+        &vc_id,
+        &vc_data,
+        &contract_id,
+        &issuer,
+        &issuer_did,
+        &None,
+        &Some(100_u64),
+        &true,
+    );
+    client.revoke(
+        &issuer,
+        &issuer,
+        &vc_id,
+        &RevocationReason::Unspecified,
+        &None,
+    );
+    env.ledger().with_mut(|li| li.timestamp = 200);
+    let m = client.verify_vc(&owner, &vc_id);
+    assert_eq!(
+        m.get(String::from_str(&env, "status")).unwrap(),
+        String::from_str(&env, "revoked")
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_issue_invalid_validity_window_panics() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let owner = Address::generate(&env);
+    client.create_vault(
+        &owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"),
+    );
+    client.authorize_issuer(&owner, &issuer, &None, &None, &vec![&env]);
+    let vc_id = String::from_str(&env, "vc-bad-window");
+    let vc_data = String::from_str(&env, "<ciphertext>");
+    let issuer_did = String::from_str(&env, "did:pkh:stellar:testnet:ISSUER");
+    client.issue(
+        &owner,
+        &vc_id,
+        &vc_data,
+        &contract_id,
+        &issuer,
+        &issuer_did,
+        &Some(500_u64),
+        &Some(500_u64),
+        &true,
+    );
+}
+
+#[test]
+fn test_suspend_and_reinstate_flow() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let owner = issue_one(&env, &client, &contract_id, &issuer, "vc-suspend");
+    let vc_id = String::from_str(&env, "vc-suspend");
+    let status_k = String::from_str(&env, "status");
+    let note = Some(String::from_str(&env, "investigation #42"));
+    client.suspend(&issuer, &issuer, &vc_id, &note);
+    let m = client.verify_vc(&owner, &vc_id);
+    assert_eq!(
+        m.get(status_k.clone()).unwrap(),
+        String::from_str(&env, "suspended")
+    );
+    assert_eq!(m.get(String::from_str(&env, "note")), note);
+    let (topics, _data) = last_event(&env, &contract_id);
+    assert_eq!(
+        topics,
+        (
+            symbol_short!("vc"),
+            symbol_short!("suspended"),
+            issuer.clone()
+        )
+            .into_val(&env)
+    );
+
+    client.reinstate(&issuer, &issuer, &vc_id);
+    assert_eq!(
+        client.verify_vc(&owner, &vc_id).get(status_k).unwrap(),
+        String::from_str(&env, "valid")
+    );
+}
+
+#[test]
+fn test_revoke_suspended_vc() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let owner = issue_one(&env, &client, &contract_id, &issuer, "vc-suspend");
+    let vc_id = String::from_str(&env, "vc-suspend");
+    client.suspend(&issuer, &issuer, &vc_id, &None);
+    client.revoke(
+        &issuer,
+        &issuer,
+        &vc_id,
+        &RevocationReason::Unspecified,
+        &None,
+    );
+    let m = client.verify_vc(&owner, &vc_id);
+    assert_eq!(
+        m.get(String::from_str(&env, "status")).unwrap(),
+        String::from_str(&env, "revoked")
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_reinstate_revoked_vc_panics() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    issue_one(&env, &client, &contract_id, &issuer, "vc-suspend");
+    let vc_id = String::from_str(&env, "vc-suspend");
+    client.suspend(&issuer, &issuer, &vc_id, &None);
+    client.revoke(
+        &issuer,
+        &issuer,
+        &vc_id,
+        &RevocationReason::Unspecified,
+        &None,
+    );
+    client.reinstate(&issuer, &issuer, &vc_id);
+}
+
+#[test]
+#[should_panic]
+fn test_suspend_twice_panics() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    issue_one(&env, &client, &contract_id, &issuer, "vc-suspend");
+    let vc_id = String::from_str(&env, "vc-suspend");
+    client.suspend(&issuer, &issuer, &vc_id, &None);
+    client.suspend(&issuer, &issuer, &vc_id, &None);
+}
+
+#[test]
+#[should_panic]
+fn test_reinstate_valid_vc_panics() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    issue_one(&env, &client, &contract_id, &issuer, "vc-suspend");
+    let vc_id = String::from_str(&env, "vc-suspend");
+    client.reinstate(&issuer, &issuer, &vc_id);
+}
+
+#[test]
+fn test_revoke_records_ledger_time() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let owner = issue_one(&env, &client, &contract_id, &issuer, "vc-timed");
+    let vc_id = String::from_str(&env, "vc-timed");
+    env.ledger().with_mut(|li| {
+        li.timestamp = 1_700_000_000;
+        li.sequence_number = 4_242;
+    });
+    client.revoke(
+        &issuer,
+        &issuer,
+        &vc_id,
+        &RevocationReason::Unspecified,
+        &None,
+    );
+    let m = client.verify_vc(&owner, &vc_id);
+    assert_eq!(
+        m.get(String::from_str(&env, "status")).unwrap(),
+        String::from_str(&env, "revoked")
+    );
+    assert_eq!(
+        m.get(String::from_str(&env, "since")).unwrap(),
+        String::from_str(&env, "1700000000")
+    );
+    assert_eq!(
+        m.get(String::from_str(&env, "since_ledger")).unwrap(),
+        String::from_str(&env, "4242")
+    );
+    assert!(m.get(String::from_str(&env, "note")).is_none());
+}
+
+#[test]
+fn test_revoke_records_reason() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let owner = issue_one(&env, &client, &contract_id, &issuer, "vc-compromised");
+    let vc_id = String::from_str(&env, "vc-compromised");
+    client.revoke(
+        &issuer,
+        &issuer,
+        &vc_id,
+        &RevocationReason::KeyCompromise,
+        &None,
+    );
+    let m = client.verify_vc(&owner, &vc_id);
+    assert_eq!(
+        m.get(String::from_str(&env, "reason")).unwrap(),
+        String::from_str(&env, "key_compromise")
+    );
+    env.as_contract(&contract_id, || {
+        match storage::read_vc_status(&env, &issuer, &vc_id) {
+            VCStatus::Revoked(_, reason) => assert_eq!(reason, RevocationReason::KeyCompromise),
+            _ => panic!("expected revoked"),
+        }
+    });
+}
+
+#[test]
+fn test_default_policy_lets_issuer_and_holder_revoke() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    assert_eq!(
+        client.get_revocation_policy(&issuer, &None),
+        RevocationPolicy::IssuerOrHolder
+    );
+    let owner = issue_one(&env, &client, &contract_id, &issuer, "vc-holder");
+    client.revoke(
+        &owner,
+        &issuer,
+        &String::from_str(&env, "vc-holder"),
+        &RevocationReason::HolderRequest,
+        &None,
+    );
+    issue_one(&env, &client, &contract_id, &issuer, "vc-issuer");
+    client.revoke(
+        &issuer,
+        &issuer,
+        &String::from_str(&env, "vc-issuer"),
+        &RevocationReason::IssuerError,
+        &None,
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_stranger_cannot_revoke() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    issue_one(&env, &client, &contract_id, &issuer, "vc-1");
+    let stranger = Address::generate(&env);
+    client.revoke(
+        &stranger,
+        &issuer,
+        &String::from_str(&env, "vc-1"),
+        &RevocationReason::Unspecified,
+        &None,
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_issuer_only_policy_blocks_holder() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    client.set_revocation_policy(&issuer, &None, &RevocationPolicy::IssuerOnly);
+    let owner = issue_one(&env, &client, &contract_id, &issuer, "vc-1");
+    client.suspend(&owner, &issuer, &String::from_str(&env, "vc-1"), &None);
+}
+
+#[test]
+#[should_panic]
+fn test_holder_only_vault_override_blocks_issuer() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let owner = issue_one(&env, &client, &contract_id, &issuer, "vc-1");
+    client.set_revocation_policy(&issuer, &Some(owner.clone()), &RevocationPolicy::HolderOnly);
+    assert_eq!(
+        client.get_revocation_policy(&issuer, &Some(owner)),
+        RevocationPolicy::HolderOnly
+    );
+    assert_eq!(
+        client.get_revocation_policy(&issuer, &None),
+        RevocationPolicy::IssuerOrHolder
+    );
+    client.revoke(
+        &issuer,
+        &issuer,
+        &String::from_str(&env, "vc-1"),
+        &RevocationReason::Unspecified,
+        &None,
+    );
+}
+
+#[test]
+fn test_contract_admin_overrides_policy() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    client.set_revocation_policy(&issuer, &None, &RevocationPolicy::HolderOnly);
+    let owner = issue_one(&env, &client, &contract_id, &issuer, "vc-1");
+    let vc_id = String::from_str(&env, "vc-1");
+    client.revoke(
+        &admin,
+        &issuer,
+        &vc_id,
+        &RevocationReason::KeyCompromise,
+        &None,
+    );
+    let m = client.verify_vc(&owner, &vc_id);
+    assert_eq!(
+        m.get(String::from_str(&env, "status")).unwrap(),
+        String::from_str(&env, "revoked")
+    );
+}
+
+#[test]
+fn test_signer_set_overrides_policy_via_admin_action() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    client.set_revocation_policy(&issuer, &None, &RevocationPolicy::HolderOnly);
+    let owner = issue_one(&env, &client, &contract_id, &issuer, "vc-1");
+    let vc_id = String::from_str(&env, "vc-1");
+    let second = Address::generate(&env);
+    run_admin_action(&client, &admin, AdminAction::AddSigner(second.clone()));
+    run_admin_action(&client, &admin, AdminAction::SetThreshold(2));
+    let status = |key: &str| {
+        client
+            .verify_vc(&owner, &vc_id)
+            .get(String::from_str(&env, key))
+            .unwrap()
+    };
+
+    // A direct call is no longer an override once the set needs two approvals.
+    assert!(client.try_suspend(&admin, &issuer, &vc_id, &None).is_err());
+
+    let id =
+        client.propose_admin_action(&admin, &AdminAction::Suspend(issuer.clone(), vc_id.clone()));
+    client.approve_admin_action(&second, &id);
+    client.execute_admin_action(&id);
+    assert_eq!(status("status"), String::from_str(&env, "suspended"));
+
+    let id = client.propose_admin_action(
+        &admin,
+        &AdminAction::Reinstate(issuer.clone(), vc_id.clone()),
+    );
+    client.approve_admin_action(&second, &id);
+    client.execute_admin_action(&id);
+    assert_eq!(status("status"), String::from_str(&env, "valid"));
+
+    let action = AdminAction::Revoke(
+        issuer.clone(),
+        vc_id.clone(),
+        RevocationReason::KeyCompromise,
+    );
+    let id = client.propose_admin_action(&second, &action);
+    client.approve_admin_action(&admin, &id);
+    client.execute_admin_action(&id);
+    assert_eq!(status("status"), String::from_str(&env, "revoked"));
+    assert_eq!(status("reason"), String::from_str(&env, "key_compromise"));
+}
+
+#[test]
+fn test_holder_after_handover_is_new_vault_admin() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    client.set_revocation_policy(&issuer, &None, &RevocationPolicy::HolderOnly);
+    let owner = issue_one(&env, &client, &contract_id, &issuer, "vc-1");
+    let vc_id = String::from_str(&env, "vc-1");
+    let new_admin = Address::generate(&env);
+    client.propose_vault_admin(&owner, &new_admin);
+    client.accept_vault_admin(&owner);
+
+    // The original owner key no longer speaks for the vault.
+    assert_eq!(
+        client.try_suspend(&owner, &issuer, &vc_id, &None).err(),
+        Some(Ok(ContractError::RevocationNotAllowed.into()))
+    );
+    client.revoke(
+        &new_admin,
+        &issuer,
+        &vc_id,
+        &RevocationReason::KeyCompromise,
+        &None,
+    );
+    assert_eq!(
+        client
+            .verify_vc(&owner, &vc_id)
+            .get(String::from_str(&env, "status"))
+            .unwrap(),
+        String::from_str(&env, "revoked")
+    );
+}
+
+#[test]
+fn test_list_vcs_paginates_hundreds() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let owner = Address::generate(&env);
+    client.create_vault(
+        &owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"),
+    );
+    client.authorize_issuer(&owner, &issuer, &None, &None, &vec![&env]);
+    issue_many(&env, &client, &contract_id, &owner, &issuer, 300);
+
+    let mut seen = std::collections::BTreeSet::new();
+    let mut cursor = 0;
+    let mut pages = 0;
+    loop {
+        env.budget().reset_default();
+        let page = client.list_vcs(&owner, &cursor, &100, &no_filter());
+        assert!(page.vcs.len() <= 50);
+        for vc in page.vcs.iter() {
+            assert!(seen.insert(vc.id.to_string()));
+        }
+        pages += 1;
+        match page.next_cursor {
+            Some(next) => cursor = next,
+            None => break,
+        }
+    }
+    assert_eq!(seen.len(), 300);
+    assert_eq!(pages, 6);
+}
+
+#[test]
+fn test_list_vcs_filters() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let owner = Address::generate(&env);
+    client.create_vault(
+        &owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"),
+    );
+    client.authorize_issuer(&owner, &issuer, &None, &None, &vec![&env]);
+    issue_many(&env, &client, &contract_id, &owner, &issuer, 200);
+    for i in [3, 10, 150] {
+        let vc_id = String::from_str(&env, &std::format!("vc-{i}"));
+        env.budget().reset_default();
+        client.revoke(
+            &issuer,
+            &issuer,
+            &vc_id,
+            &RevocationReason::Superseded,
+            &None,
+        );
+    }
+
+    let odd = VCFilter {
+        issuer_did: Some(String::from_str(&env, "did:odd")),
+        ..no_filter()
+    };
+    let mut count = 0;
+    let mut cursor = Some(0);
+    while let Some(c) = cursor {
+        env.budget().reset_default();
+        let page = client.list_vcs(&owner, &c, &50, &odd);
+        for vc in page.vcs.iter() {
+            assert_eq!(vc.issuer_did, String::from_str(&env, "did:odd"));
+        }
+        count += page.vcs.len();
+        cursor = page.next_cursor;
+    }
+    assert_eq!(count, 100);
+
+    let revoked = VCFilter {
+        status: Some(String::from_str(&env, "revoked")),
+        ..no_filter()
+    };
+    let mut ids = std::vec::Vec::new();
+    let mut cursor = Some(0);
+    while let Some(c) = cursor {
+        env.budget().reset_default();
+        let page = client.list_vcs(&owner, &c, &50, &revoked);
+        ids.extend(page.vcs.iter().map(|vc| vc.id.to_string()));
+        cursor = page.next_cursor;
+    }
+    ids.sort();
+    assert_eq!(ids, ["vc-10", "vc-150", "vc-3"]);
+
+    let other_contract = VCFilter {
+        issuance_contract: Some(Address::generate(&env)),
+        ..no_filter()
+    };
+    env.budget().reset_default();
+    let page = client.list_vcs(&owner, &0, &50, &other_contract);
+    assert_eq!(page.vcs.len(), 0);
+}
+
+#[test]
+#[should_panic]
+fn test_list_vcs_zero_limit_panics() {
+    let (env, admin, _issuer, _contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let owner = Address::generate(&env);
+    client.create_vault(
+        &owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"),
+    );
+    client.list_vcs(&owner, &0, &0, &no_filter());
+}
+
+/// CPU and memory charged for issuing one more VC into a vault already holding `size` VCs.
+/// A filler vault keeps the total number of ledger entries at 200 so that only the target
+/// vault's layout differs between runs (the test host's storage map grows with total entries).
+fn issue_cost_at(size: u32) -> (u64, u64) {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let owner = Address::generate(&env);
+    let filler = Address::generate(&env);
+    client.create_vault(
+        &owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"),
+    );
+    client.create_vault(
+        &filler,
+        &String::from_str(&env, "did:pkh:stellar:testnet:FILLER"),
+    );
+    client.authorize_issuer(&owner, &issuer, &None, &None, &vec![&env]);
+    let filler_issuer = Address::generate(&env);
+    client.authorize_issuer(&filler, &filler_issuer, &None, &None, &vec![&env]);
+    issue_many(&env, &client, &contract_id, &owner, &issuer, size);
+    issue_many(
+        &env,
+        &client,
+        &contract_id,
+        &filler,
+        &filler_issuer,
+        200 - size,
+    );
+
+    env.budget().reset_default();
+    client.issue(
+        &owner,
+        &String::from_str(&env, "vc-next"),
+        &String::from_str(&env, "<ciphertext>"),
+        &contract_id,
+        &issuer,
+        &String::from_str(&env, "did:even"),
+        &None,
+        &None,
+        &true,
+    );
+    (
+        env.budget().cpu_instruction_cost(),
+        env.budget().memory_bytes_cost(),
+    )
+}
+
+#[test]
+fn test_issue_cost_independent_of_vault_size() {
+    let (cpu_10, mem_10) = issue_cost_at(10);
+    let (cpu_190, mem_190) = issue_cost_at(190);
+    // A page holds at most INDEX_PAGE_SIZE IDs, so issuing reads and writes one page however
+    // large the vault is; only the TTL bump grows, by one entry per page.
+    assert!(cpu_190 < cpu_10 * 11 / 10);
+    assert!(mem_190 < mem_10 * 11 / 10);
+}
+
+#[test]
+fn test_vault_index_remove_keeps_remaining_ids() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let from_owner = Address::generate(&env);
+    let to_owner = Address::generate(&env);
+    client.create_vault(
+        &from_owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:FROM"),
+    );
+    client.create_vault(
+        &to_owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:TO"),
+    );
+    client.authorize_issuer(&from_owner, &issuer, &None, &None, &vec![&env]);
+    issue_many(&env, &client, &contract_id, &from_owner, &issuer, 70);
+
+    // vc-3 is on the first page, vc-69 is last on the second; removal moves vc-69 into vc-3's slot.
+    for id in ["vc-3", "vc-69", "vc-0"] {
+        env.budget().reset_default();
+        client.push(&from_owner, &to_owner, &String::from_str(&env, id));
+    }
+
+    env.budget().reset_default();
+    let mut ids: std::vec::Vec<_> = client
+        .list_vc_ids(&from_owner)
+        .iter()
+        .map(|id| id.to_string())
+        .collect();
+    ids.sort();
+    let mut expected: std::vec::Vec<_> = (1..69)
+        .filter(|i| *i != 3)
+        .map(|i| std::format!("vc-{i}"))
+        .collect();
+    expected.sort();
+    assert_eq!(ids, expected);
+    assert_eq!(client.list_vc_ids(&to_owner).len(), 3);
+    env.as_contract(&contract_id, || {
+        assert_eq!(storage::read_vault_vc_count(&env, &from_owner), 67);
+    });
+}
+
+#[test]
+fn test_list_vcs_cursor_not_stable_across_removal() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let owner = Address::generate(&env);
+    client.create_vault(
+        &owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"),
+    );
+    client.authorize_issuer(&owner, &issuer, &None, &None, &vec![&env]);
+    issue_many(&env, &client, &contract_id, &owner, &issuer, 5);
+    let ids = |page: VCPage| -> std::vec::Vec<std::string::String> {
+        page.vcs.iter().map(|vc| vc.id.to_string()).collect()
+    };
+
+    let first = client.list_vcs(&owner, &0, &2, &no_filter());
+    assert_eq!(ids(first.clone()), ["vc-0", "vc-1"]);
+
+    // Removing vc-0 moves the newest ID, vc-4, into position 0, behind the cursor.
+    client.delete_vc(&owner, &String::from_str(&env, "vc-0"), &false);
+    let second = client.list_vcs(&owner, &first.next_cursor.unwrap(), &2, &no_filter());
+    assert_eq!(ids(second.clone()), ["vc-2", "vc-3"]);
+    assert_eq!(second.next_cursor, None);
+
+    // A fresh walk sees everything.
+    let all = client.list_vcs(&owner, &0, &10, &no_filter());
+    assert_eq!(ids(all), ["vc-4", "vc-1", "vc-2", "vc-3"]);
+    let listed: std::vec::Vec<_> = client
+        .list_vc_ids(&owner)
+        .iter()
+        .map(|id| id.to_string())
+        .collect();
+    assert_eq!(listed, ["vc-4", "vc-1", "vc-2", "vc-3"]);
+}
+
+#[test]
+fn test_migrate_vault_index() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let owner = Address::generate(&env);
+    client.create_vault(
+        &owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"),
+    );
+    client.authorize_issuer(&owner, &issuer, &None, &None, &vec![&env]);
+    issue_many(&env, &client, &contract_id, &owner, &issuer, 3);
+
+    // Rebuild the pre-paging layout: newest-first vector, no paged index. "vc-gone" has no
+    // payload left.
+    env.as_contract(&contract_id, || {
+        let legacy = vec![
+            &env,
+            String::from_str(&env, "vc-2"),
+            String::from_str(&env, "vc-gone"),
+            String::from_str(&env, "vc-1"),
+            String::from_str(&env, "vc-0"),
+        ];
+        for id in legacy.iter() {
+            storage::remove_vault_vc_id(&env, &owner, &id);
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::VaultVCIds(owner.clone()), &legacy);
+    });
+    // Reads see the legacy index until it is migrated.
+    assert_eq!(client.list_vc_ids(&owner).len(), 4);
+    let page = client.list_vcs(&owner, &0, &10, &no_filter());
+    assert_eq!(page.vcs.len(), 3);
+    assert_eq!(page.vcs.get_unchecked(0).id, String::from_str(&env, "vc-0"));
+    // Closing now would orphan the payloads the legacy vector lists.
+    assert_eq!(
+        client.try_close_vault(&owner, &50).err(),
+        Some(Ok(ContractError::LegacyIndexNotMigrated.into()))
+    );
+
+    client.migrate_vault_index(&owner);
+    let (topics, data) = last_event(&env, &contract_id);
+    assert_eq!(
+        topics,
+        (
+            symbol_short!("contract"),
+            symbol_short!("mig_index"),
+            owner.clone()
+        )
+            .into_val(&env)
+    );
+    let count: u32 = data.into_val(&env);
+    assert_eq!(count, 3);
+    assert_eq!(
+        client.list_vc_ids(&owner),
+        vec![
+            &env,
+            String::from_str(&env, "vc-0"),
+            String::from_str(&env, "vc-1"),
+            String::from_str(&env, "vc-2"),
+        ]
+    );
+    env.as_contract(&contract_id, || {
+        assert!(storage::read_legacy_vault_vc_ids(&env, &owner).is_none());
+    });
+    assert_eq!(client.close_vault(&owner, &50), 0);
+    env.as_contract(&contract_id, || {
+        assert!(storage::read_vault_vc(&env, &owner, &String::from_str(&env, "vc-0")).is_none());
+    });
+}
+
+#[test]
+#[should_panic]
+fn test_migrate_vault_index_twice_panics() {
+    let (env, admin, _issuer, _contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let owner = Address::generate(&env);
+    client.create_vault(
+        &owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"),
+    );
+    client.migrate_vault_index(&owner);
+}
+
+#[test]
+fn test_first_write_migrates_legacy_vault_index() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let owner = Address::generate(&env);
+    client.create_vault(
+        &owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"),
+    );
+    client.authorize_issuer(&owner, &issuer, &None, &None, &vec![&env]);
+    issue_many(&env, &client, &contract_id, &owner, &issuer, 2);
+    env.as_contract(&contract_id, || {
+        let legacy = vec![
+            &env,
+            String::from_str(&env, "vc-1"),
+            String::from_str(&env, "vc-gone"),
+            String::from_str(&env, "vc-0"),
+        ];
+        for id in legacy.iter() {
+            storage::remove_vault_vc_id(&env, &owner, &id);
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::VaultVCIds(owner.clone()), &legacy);
+    });
+
+    client.delete_vc(&owner, &String::from_str(&env, "vc-0"), &false);
+    assert_eq!(
+        client.list_vc_ids(&owner),
+        vec![&env, String::from_str(&env, "vc-1")]
+    );
+    env.as_contract(&contract_id, || {
+        assert!(storage::read_legacy_vault_vc_ids(&env, &owner).is_none());
+    });
+}
+
+#[test]
+fn test_issuer_quota_and_vault_cap() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let owner = Address::generate(&env);
+    let other = Address::generate(&env);
+    client.create_vault(
+        &owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"),
+    );
+    client.create_vault(
+        &other,
+        &String::from_str(&env, "did:pkh:stellar:testnet:OTHER"),
+    );
+    let issuer2 = Address::generate(&env);
+    client.add_issuers(&owner, &vec![&env, issuer.clone(), issuer2.clone()]);
+    assert_eq!(client.get_remaining_quota(&owner, &issuer), None);
+
+    client.set_issuer_quota(&owner, &issuer, &Some(2));
+    let (topics, _) = last_event(&env, &contract_id);
+    assert_eq!(
+        topics,
+        (
+            symbol_short!("vault"),
+            symbol_short!("quota"),
+            owner.clone()
+        )
+            .into_val(&env)
+    );
+    client.set_vault_cap(&owner, &Some(3));
+    assert_eq!(client.get_remaining_quota(&owner, &issuer), Some(2));
+    assert_eq!(client.get_remaining_quota(&owner, &issuer2), Some(3));
+
+    issue_many(&env, &client, &contract_id, &owner, &issuer, 2);
+    assert_eq!(client.get_remaining_quota(&owner, &issuer), Some(0));
+    assert_eq!(client.get_remaining_quota(&owner, &issuer2), Some(1));
+
+    // Moving a credential out frees the issuer's quota and a vault slot.
+    client.push(&owner, &other, &String::from_str(&env, "vc-0"));
+    assert_eq!(client.get_remaining_quota(&owner, &issuer), Some(1));
+    assert_eq!(client.get_remaining_quota(&owner, &issuer2), Some(2));
+
+    client.set_issuer_quota(&owner, &issuer, &None);
+    assert_eq!(client.get_remaining_quota(&owner, &issuer), Some(2));
+}
+
+#[test]
+#[should_panic]
+fn test_issue_over_issuer_quota_panics() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let owner = Address::generate(&env);
+    client.create_vault(
+        &owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"),
+    );
+    client.authorize_issuer(&owner, &issuer, &None, &None, &vec![&env]);
+    client.set_issuer_quota(&owner, &issuer, &Some(2));
+    issue_many(&env, &client, &contract_id, &owner, &issuer, 3);
+}
+
+#[test]
+#[should_panic]
+fn test_issue_over_vault_cap_panics() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let owner = Address::generate(&env);
+    client.create_vault(
+        &owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"),
+    );
+    client.authorize_issuer(&owner, &issuer, &None, &None, &vec![&env]);
+    client.set_vault_cap(&owner, &Some(1));
+    issue_many(&env, &client, &contract_id, &owner, &issuer, 2);
+}
+
+#[test]
+fn test_inbox_accept_and_reject() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let owner = Address::generate(&env);
+    client.create_vault(
+        &owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"),
+    );
+    client.authorize_issuer(&owner, &issuer, &None, &None, &vec![&env]);
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    client.set_inbox(&owner, &Some(600));
+
+    issue_many(&env, &client, &contract_id, &owner, &issuer, 2);
+    let (topics, data) = last_event(&env, &contract_id);
+    assert_eq!(
+        topics,
+        (symbol_short!("vc"), symbol_short!("pending"), owner.clone()).into_val(&env)
+    );
+    let (vc_id, expires_at): (String, u64) = data.into_val(&env);
+    assert_eq!((vc_id, expires_at), (String::from_str(&env, "vc-1"), 1_600));
+    assert_eq!(client.list_vc_ids(&owner).len(), 0);
+    let page = client.list_pending(&owner, &0, &10);
+    assert_eq!(page.items.len(), 2);
+    assert_eq!(page.items.get_unchecked(0).sender, None);
+
+    let vc0 = String::from_str(&env, "vc-0");
+    let vc1 = String::from_str(&env, "vc-1");
+    client.accept_vc(&owner, &vc0);
+    client.reject_vc(&owner, &vc1);
+    assert_eq!(client.list_vc_ids(&owner), vec![&env, vc0.clone()]);
+    assert_eq!(client.list_pending(&owner, &0, &10).items.len(), 0);
+    assert!(client.get_vc(&owner, &vc0).is_some());
+
+    // Turning the inbox off delivers straight into the vault again.
+    client.set_inbox(&owner, &None);
+    client.issue(
+        &owner,
+        &String::from_str(&env, "vc-2"),
+        &String::from_str(&env, "<ciphertext>"),
+        &contract_id,
+        &issuer,
+        &String::from_str(&env, "did:even"),
+        &None,
+        &None,
+        &true,
+    );
+    assert_eq!(client.list_vc_ids(&owner).len(), 2);
+}
+
+#[test]
+fn test_inbox_counts_against_quota_and_cap() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let sender = issue_one(&env, &client, &contract_id, &issuer, "vc-push");
+    let owner = Address::generate(&env);
+    client.create_vault(
+        &owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"),
+    );
+    client.authorize_issuer(&owner, &issuer, &None, &None, &vec![&env]);
+    client.set_issuer_quota(&owner, &issuer, &Some(1));
+    client.set_vault_cap(&owner, &Some(1));
+    client.set_inbox(&owner, &Some(600));
+
+    issue_many(&env, &client, &contract_id, &owner, &issuer, 1);
+    assert_eq!(client.get_remaining_quota(&owner, &issuer), Some(0));
+    let quota_exceeded = Some(Ok(ContractError::VaultQuotaExceeded.into()));
+    let result = client.try_issue(
+        &owner,
+        &String::from_str(&env, "vc-1"),
+        &String::from_str(&env, "<ciphertext>"),
+        &contract_id,
+        &issuer,
+        &String::from_str(&env, "did:odd"),
+        &None,
+        &None,
+        &true,
+    );
+    assert_eq!(result.err(), quota_exceeded);
+    let vc_push = String::from_str(&env, "vc-push");
+    assert_eq!(
+        client.try_push(&sender, &owner, &vc_push).err(),
+        quota_exceeded
+    );
+
+    // Rejecting frees the slot; a lowered cap is checked again on accept.
+    client.reject_vc(&owner, &String::from_str(&env, "vc-0"));
+    assert_eq!(client.get_remaining_quota(&owner, &issuer), Some(1));
+    client.push(&sender, &owner, &vc_push);
+    client.set_vault_cap(&owner, &Some(0));
+    assert_eq!(client.try_accept_vc(&owner, &vc_push).err(), quota_exceeded);
+    client.set_vault_cap(&owner, &Some(1));
+    client.accept_vc(&owner, &vc_push);
+    assert_eq!(client.list_vc_ids(&owner), vec![&env, vc_push]);
+    assert_eq!(client.get_remaining_quota(&owner, &issuer), Some(0));
+}
+
+#[test]
+fn test_inbox_rejected_push_returns_to_sender() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let from_owner = issue_one(&env, &client, &contract_id, &issuer, "vc-push");
+    let to_owner = Address::generate(&env);
+    client.create_vault(
+        &to_owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:TO"),
+    );
+    client.set_inbox(&to_owner, &Some(600));
+    let vc_id = String::from_str(&env, "vc-push");
+
+    client.push(&from_owner, &to_owner, &vc_id);
+    assert_eq!(client.list_vc_ids(&from_owner).len(), 0);
+    let pending = client
+        .list_pending(&to_owner, &0, &10)
+        .items
+        .get_unchecked(0);
+    assert_eq!(pending.sender, Some(from_owner.clone()));
+
+    client.reject_vc(&to_owner, &vc_id);
+    let (_, data) = last_event(&env, &contract_id);
+    let (_, returned_to): (String, Option<Address>) = data.into_val(&env);
+    assert_eq!(returned_to, Some(from_owner.clone()));
+    assert_eq!(client.list_vc_ids(&from_owner), vec![&env, vc_id]);
+}
+
+#[test]
+fn test_registry_owner_follows_inbox_delivery() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let owner = Address::generate(&env);
+    client.create_vault(
+        &owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"),
+    );
+    client.authorize_issuer(&owner, &issuer, &None, &None, &vec![&env]);
+    client.set_inbox(&owner, &Some(600));
+    issue_many(&env, &client, &contract_id, &owner, &issuer, 2);
+    let vc0 = String::from_str(&env, "vc-0");
+    let vc1 = String::from_str(&env, "vc-1");
+    let registry = |vc_id: &String| {
+        env.as_contract(&contract_id, || {
+            (
+                storage::read_vc_owner(&env, &issuer, vc_id),
+                storage::has_vc_status(&env, &issuer, vc_id),
+            )
+        })
+    };
+    assert_eq!(registry(&vc0), (None, true));
+
+    client.accept_vc(&owner, &vc0);
+    assert_eq!(registry(&vc0), (Some(owner.clone()), true));
+    // A rejected issuance never reached a holder, so the issuer may use the ID again.
+    client.reject_vc(&owner, &vc1);
+    assert_eq!(registry(&vc1), (None, false));
+    client.issue(
+        &owner,
+        &vc1,
+        &String::from_str(&env, "<ciphertext>"),
+        &contract_id,
+        &issuer,
+        &String::from_str(&env, "did:odd"),
+        &None,
+        &None,
+        &true,
+    );
+
+    // A pushed credential has no owner while pending and is the sender's again once returned.
+    let to_owner = Address::generate(&env);
+    client.create_vault(
+        &to_owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:TO"),
+    );
+    client.set_inbox(&to_owner, &Some(600));
+    client.push(&owner, &to_owner, &vc0);
+    assert_eq!(registry(&vc0), (None, true));
+    client.reject_vc(&to_owner, &vc0);
+    assert_eq!(registry(&vc0), (Some(owner.clone()), true));
+}
+
+#[test]
+fn test_migrate_vault_bypasses_inbox() {
+    let (env, admin, _issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let owner = Address::generate(&env);
+    client.create_vault(
+        &owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"),
+    );
+    client.set_inbox(&owner, &Some(600));
+    let vc_id = String::from_str(&env, "vc-legacy");
+    env.as_contract(&contract_id, || {
+        let legacy_vc = LegacyVerifiableCredential {
+            id: vc_id.clone(),
+            data: String::from_str(&env, "<ciphertext>"),
+            issuance_contract: contract_id.clone(),
+            issuer_did: String::from_str(&env, "did:pkh:stellar:testnet:ISSUER"),
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::LegacyVaultVCs(owner.clone()), &vec![&env, legacy_vc]);
+    });
+
+    client.migrate(&Some(owner.clone()));
+    assert_eq!(client.list_vc_ids(&owner), vec![&env, vc_id]);
+    assert_eq!(client.list_pending(&owner, &0, &10).items.len(), 0);
+}
+
+#[test]
+#[should_panic]
+fn test_accept_expired_pending_vc_panics() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let owner = Address::generate(&env);
+    client.create_vault(
+        &owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"),
+    );
+    client.authorize_issuer(&owner, &issuer, &None, &None, &vec![&env]);
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    client.set_inbox(&owner, &Some(600));
+    issue_many(&env, &client, &contract_id, &owner, &issuer, 1);
+    env.ledger().with_mut(|li| li.timestamp = 1_601);
+    client.accept_vc(&owner, &String::from_str(&env, "vc-0"));
+}
+
+#[test]
+fn test_push_requires_recipient_signature() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let from_owner = issue_one(&env, &client, &contract_id, &issuer, "vc-push");
+    let to_owner = Address::generate(&env);
+    client.create_vault(
+        &to_owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:TO"),
+    );
+
+    client.push(&from_owner, &to_owner, &String::from_str(&env, "vc-push"));
+    let signers: std::vec::Vec<Address> = env.auths().into_iter().map(|(addr, _)| addr).collect();
+    assert_eq!(signers, [from_owner, to_owner]);
+}
+
+#[test]
+fn test_push_from_allowed_sender_skips_recipient_signature() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let from_owner = issue_one(&env, &client, &contract_id, &issuer, "vc-push");
+    let to_owner = Address::generate(&env);
+    client.create_vault(
+        &to_owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:TO"),
+    );
+    client.set_transfer_allowed(&to_owner, &from_owner, &true);
+    assert!(client.is_transfer_allowed(&to_owner, &from_owner));
+
+    client.push(&from_owner, &to_owner, &String::from_str(&env, "vc-push"));
+    let signers: std::vec::Vec<Address> = env.auths().into_iter().map(|(addr, _)| addr).collect();
+    assert_eq!(signers.len(), 1);
+    assert_eq!(signers[0], from_owner);
+
+    client.set_transfer_allowed(&to_owner, &from_owner, &false);
+    assert!(!client.is_transfer_allowed(&to_owner, &from_owner));
+}
+
+#[test]
+#[should_panic]
+fn test_push_without_recipient_signature_panics() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let from_owner = issue_one(&env, &client, &contract_id, &issuer, "vc-push");
+    let to_owner = Address::generate(&env);
+    client.create_vault(
+        &to_owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:TO"),
+    );
+    let vc_id = String::from_str(&env, "vc-push");
+    env.mock_auths(&[MockAuth {
+        address: &from_owner,
+        invoke: &MockAuthInvoke {
+            contract: &contract_id,
+            fn_name: "push",
+            args: (from_owner.clone(), to_owner.clone(), vc_id.clone()).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    client.push(&from_owner, &to_owner, &vc_id);
+}
+
+#[test]
+fn test_push_ignores_source_issuer_list_and_syncs_registry_owner() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let from_owner = issue_one(&env, &client, &contract_id, &issuer, "vc-push");
+    let to_owner = Address::generate(&env);
+    client.create_vault(
+        &to_owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:TO"),
+    );
+    let vc_id = String::from_str(&env, "vc-push");
+    // The issuer takes no part in a transfer, so losing source authorization does not block it.
+    client.revoke_issuer(&from_owner, &issuer);
+
+    client.push(&from_owner, &to_owner, &vc_id);
+    env.as_contract(&contract_id, || {
+        assert_eq!(
+            storage::read_vc_owner(&env, &issuer, &vc_id),
+            Some(to_owner.clone())
+        );
+    });
+
+    // The new holder now acts as holder under the revocation policy.
+    client.set_revocation_policy(&issuer, &None, &RevocationPolicy::HolderOnly);
+    client.revoke(
+        &to_owner,
+        &issuer,
+        &vc_id,
+        &RevocationReason::HolderRequest,
+        &None,
+    );
+}
+
+#[test]
+fn test_non_transferable_vc_exposed_in_verify() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let owner = issue_one(&env, &client, &contract_id, &issuer, "vc-free");
+    let vc_id = String::from_str(&env, "vc-kyc");
+    client.issue(
+        &owner,
+        &vc_id,
+        &String::from_str(&env, "<ciphertext>"),
+        &contract_id,
+        &issuer,
+        &String::from_str(&env, "did:pkh:stellar:testnet:ISSUER"),
+        &None,
+        &None,
+        &false,
+    );
+    assert!(!client.get_vc(&owner, &vc_id).unwrap().transferable);
+    let key = String::from_str(&env, "transferable");
+    let m = client.verify_vc(&owner, &vc_id);
+    assert_eq!(m.get(key.clone()).unwrap(), String::from_str(&env, "false"));
+    let m = client.verify_vc(&owner, &String::from_str(&env, "vc-free"));
+    assert_eq!(m.get(key).unwrap(), String::from_str(&env, "true"));
+}
+
+#[test]
+#[should_panic]
+fn test_push_non_transferable_vc_panics() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let from_owner = Address::generate(&env);
+    let to_owner = Address::generate(&env);
+    client.create_vault(
+        &from_owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:FROM"),
+    );
+    client.create_vault(
+        &to_owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:TO"),
+    );
+    client.authorize_issuer(&from_owner, &issuer, &None, &None, &vec![&env]);
+    let vc_id = String::from_str(&env, "vc-kyc");
+    client.issue(
+        &from_owner,
+        &vc_id,
+        &String::from_str(&env, "<ciphertext>"),
+        &contract_id,
+        &issuer,
+        &String::from_str(&env, "did:pkh:stellar:testnet:ISSUER"),
+        &None,
+        &None,
+        &false,
+    );
+    client.push(&from_owner, &to_owner, &vc_id);
+}
+
+#[test]
+fn test_delete_vc_keeps_registry_entry() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let owner = issue_one(&env, &client, &contract_id, &issuer, "vc-1");
+    let vc_id = String::from_str(&env, "vc-1");
+    client.set_issuer_quota(&owner, &issuer, &Some(1));
+    assert_eq!(client.get_remaining_quota(&owner, &issuer), Some(0));
+
+    client.delete_vc(&owner, &vc_id, &false);
+    let (topics, _) = last_event(&env, &contract_id);
+    assert_eq!(
+        topics,
+        (symbol_short!("vc"), symbol_short!("deleted"), owner.clone()).into_val(&env)
+    );
+    assert!(client.get_vc(&owner, &vc_id).is_none());
+    assert_eq!(client.list_vc_ids(&owner).len(), 0);
+    assert_eq!(client.get_vc_tombstone(&owner, &vc_id), None);
+    assert_eq!(client.get_remaining_quota(&owner, &issuer), Some(1));
+    env.as_contract(&contract_id, || {
+        assert!(storage::read_vc_status(&env, &issuer, &vc_id) == VCStatus::Valid);
+    });
+}
+
+#[test]
+fn test_delete_vc_with_tombstone() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let owner = issue_one(&env, &client, &contract_id, &issuer, "vc-1");
+    let vc_id = String::from_str(&env, "vc-1");
+    env.ledger().with_mut(|li| li.timestamp = 5_000);
+    client.delete_vc(&owner, &vc_id, &true);
+
+    let tombstone = client.get_vc_tombstone(&owner, &vc_id).unwrap();
+    assert_eq!(tombstone.issuer, issuer);
+    assert_eq!(tombstone.deleted_at, 5_000);
+    assert!(client.get_vc(&owner, &vc_id).is_none());
+
+    // Another issuer cannot write the tombstoned ID back into the vault.
+    let issuer2 = Address::generate(&env);
+    client.authorize_issuer(&owner, &issuer2, &None, &None, &vec![&env]);
+    let res = client.try_issue(
+        &owner,
+        &vc_id,
+        &String::from_str(&env, "<ciphertext>"),
+        &contract_id,
+        &issuer2,
+        &String::from_str(&env, "did:pkh:stellar:testnet:ISSUER2"),
+        &None,
+        &None,
+        &true,
+    );
+    assert!(res.is_err());
+}
+
+#[test]
+#[should_panic]
+fn test_delete_missing_vc_panics() {
+    let (env, admin, _issuer, _contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let owner = Address::generate(&env);
+    client.create_vault(
+        &owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"),
+    );
+    client.delete_vc(&owner, &String::from_str(&env, "vc-1"), &false);
+}
+
+#[test]
+fn test_reactivate_vault_after_cooldown() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    client.set_reactivation_cooldown(&3_600);
+    assert_eq!(client.get_reactivation_cooldown(), 3_600);
+    let owner = issue_one(&env, &client, &contract_id, &issuer, "vc-1");
+    env.ledger().with_mut(|li| li.timestamp = 10_000);
+    client.revoke_vault(&owner);
+
+    env.ledger().with_mut(|li| li.timestamp = 13_599);
+    assert!(client.try_reactivate_vault(&owner).is_err());
+    env.ledger().with_mut(|li| li.timestamp = 13_600);
+    client.reactivate_vault(&owner);
+    let (topics, _) = last_event(&env, &contract_id);
+    assert_eq!(
+        topics,
+        (
+            symbol_short!("vault"),
+            symbol_short!("restored"),
+            owner.clone()
+        )
+            .into_val(&env)
+    );
+
+    // Writes work again.
+    client.issue(
+        &owner,
+        &String::from_str(&env, "vc-2"),
+        &String::from_str(&env, "<ciphertext>"),
+        &contract_id,
+        &issuer,
+        &String::from_str(&env, "did:pkh:stellar:testnet:ISSUER"),
+        &None,
+        &None,
+        &true,
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_reactivate_active_vault_panics() {
+    let (env, admin, _issuer, _contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let owner = Address::generate(&env);
+    client.create_vault(
+        &owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"),
+    );
+    client.reactivate_vault(&owner);
+}
+
+#[test]
+fn test_close_vault_in_batches() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let owner = Address::generate(&env);
+    client.create_vault(
+        &owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"),
+    );
+    client.authorize_issuer(&owner, &issuer, &None, &None, &vec![&env]);
+    client.set_issuer_quota(&owner, &issuer, &Some(100));
+    issue_many(&env, &client, &contract_id, &owner, &issuer, 70);
+
+    // 70 credentials + 1 issuer entry; at most 50 removed per call.
+    env.budget().reset_default();
+    assert_eq!(client.close_vault(&owner, &100), 21);
+    let (topics, _) = last_event(&env, &contract_id);
+    assert_eq!(
+        topics,
+        (
+            symbol_short!("vault"),
+            symbol_short!("closing"),
+            owner.clone()
+        )
+            .into_val(&env)
+    );
+    assert!(client
+        .try_issue(
+            &owner,
+            &String::from_str(&env, "vc-new"),
+            &String::from_str(&env, "<ciphertext>"),
+            &contract_id,
+            &issuer,
+            &String::from_str(&env, "did:even"),
+            &None,
+            &None,
+            &true,
+        )
+        .is_err());
+
+    env.budget().reset_default();
+    assert_eq!(client.close_vault(&owner, &50), 0);
+    let (topics, _) = last_event(&env, &contract_id);
+    assert_eq!(
+        topics,
+        (
+            symbol_short!("vault"),
+            symbol_short!("closed"),
+            owner.clone()
+        )
+            .into_val(&env)
+    );
+    env.as_contract(&contract_id, || {
+        assert!(!storage::has_vault_admin(&env, &owner));
+        assert!(storage::read_vault_vc(&env, &owner, &String::from_str(&env, "vc-3")).is_none());
+        assert!(storage::read_issuer_quota(&env, &owner, &issuer).is_none());
+        assert_eq!(storage::read_issuer_usage(&env, &owner, &issuer), 0);
+        // The issuer's status registry is untouched.
+        assert!(
+            storage::read_vc_status(&env, &issuer, &String::from_str(&env, "vc-3"))
+                == VCStatus::Valid
+        );
+    });
+
+    // The owner can start over with a fresh vault.
+    client.create_vault(
+        &owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"),
+    );
+    assert_eq!(client.list_vc_ids(&owner).len(), 0);
+}
+
+#[test]
+fn test_recreated_vault_drops_old_allowlist_and_settings() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let from_owner = issue_one(&env, &client, &contract_id, &issuer, "vc-push");
+    let to_owner = Address::generate(&env);
+    let did = String::from_str(&env, "did:pkh:stellar:testnet:TO");
+    client.create_vault(&to_owner, &did);
+    client.set_transfer_allowed(&to_owner, &from_owner, &true);
+    client.set_revocation_policy(&issuer, &Some(to_owner.clone()), &RevocationPolicy::HolderOnly);
+    let other_issuer = Address::generate(&env);
+    client.set_issuer_quota(&to_owner, &other_issuer, &Some(1));
+    assert_eq!(client.close_vault(&to_owner, &50), 0);
+
+    client.create_vault(&to_owner, &did);
+    assert!(!client.is_transfer_allowed(&to_owner, &from_owner));
+    assert_eq!(
+        client.get_revocation_policy(&issuer, &Some(to_owner.clone())),
+        RevocationPolicy::IssuerOrHolder
+    );
+    env.as_contract(&contract_id, || {
+        assert!(storage::read_issuer_quota(&env, &to_owner, &other_issuer).is_none());
+    });
+
+    // The old consent is gone: the push needs the new vault's signature again.
+    client.push(&from_owner, &to_owner, &String::from_str(&env, "vc-push"));
+    let signers: std::vec::Vec<Address> = env.auths().into_iter().map(|(addr, _)| addr).collect();
+    assert_eq!(signers, [from_owner, to_owner]);
+}
+
+#[test]
+fn test_guardian_recovery_after_lost_key() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let owner = issue_one(&env, &client, &contract_id, &issuer, "vc-1");
+    let guardians = vec![
+        &env,
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    client.set_guardians(&owner, &guardians, &2, &86_400);
+    assert_eq!(client.get_guardians(&owner).unwrap().threshold, 2);
+
+    // A handover proposed before the key was lost does not survive the recovery.
+    client.propose_vault_admin(&owner, &Address::generate(&env));
+
+    // The owner's key is lost; two guardians rotate the admin to a fresh key.
+    let new_admin = Address::generate(&env);
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    client.approve_recovery(&owner, &guardians.get_unchecked(0), &new_admin);
+    assert_eq!(client.get_recoveries(&owner).get_unchecked(0).executable_at, None);
+    assert!(client.try_execute_recovery(&owner).is_err());
+
+    client.approve_recovery(&owner, &guardians.get_unchecked(2), &new_admin);
+    let (topics, _) = last_event(&env, &contract_id);
+    assert_eq!(
+        topics,
+        (
+            symbol_short!("vault"),
+            symbol_short!("rec_appr"),
+            owner.clone()
+        )
+            .into_val(&env)
+    );
+    assert_eq!(
+        client.get_recoveries(&owner).get_unchecked(0).executable_at,
+        Some(87_400)
+    );
+    env.ledger().with_mut(|li| li.timestamp = 87_399);
+    assert!(client.try_execute_recovery(&owner).is_err());
+
+    env.ledger().with_mut(|li| li.timestamp = 87_400);
+    client.execute_recovery(&owner);
+    let (topics, data) = last_event(&env, &contract_id);
+    assert_eq!(
+        topics,
+        (
+            symbol_short!("vault"),
+            symbol_short!("recovered"),
+            owner.clone()
+        )
+            .into_val(&env)
+    );
+    let recovered: Address = data.into_val(&env);
+    assert_eq!(recovered, new_admin);
+    assert!(client.get_recoveries(&owner).is_empty());
+    assert_eq!(client.get_pending_vault_admin(&owner), None);
+
+    // The new admin key now controls the vault.
+    client.authorize_issuer(&owner, &Address::generate(&env), &None, &None, &vec![&env]);
+    let signers: std::vec::Vec<Address> = env.auths().into_iter().map(|(addr, _)| addr).collect();
+    assert_eq!(signers.len(), 1);
+    assert_eq!(signers[0], new_admin);
+}
+
+#[test]
+fn test_recovery_delay_minimum_and_expiry() {
+    let (env, admin, _issuer, _contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let owner = Address::generate(&env);
+    client.create_vault(
+        &owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"),
+    );
+    let guardians = vec![&env, Address::generate(&env), Address::generate(&env)];
+    assert!(client
+        .try_set_guardians(&owner, &guardians, &1, &0)
+        .is_err());
+    assert!(client
+        .try_set_guardians(&owner, &guardians, &1, &86_399)
+        .is_err());
+    client.set_guardians(&owner, &guardians, &2, &86_400);
+
+    // A lone approval lapses after 7 days; a new request can then name another admin.
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    client.approve_recovery(
+        &owner,
+        &guardians.get_unchecked(0),
+        &Address::generate(&env),
+    );
+    assert_eq!(
+        client.get_recoveries(&owner).get_unchecked(0).expires_at,
+        1_000 + 7 * 86_400
+    );
+    env.ledger()
+        .with_mut(|li| li.timestamp = 1_000 + 7 * 86_400 + 1);
+    let new_admin = Address::generate(&env);
+    client.approve_recovery(&owner, &guardians.get_unchecked(1), &new_admin);
+    let request = client.get_recoveries(&owner).get_unchecked(0);
+    assert_eq!(request.approvals, vec![&env, guardians.get_unchecked(1)]);
+    assert_eq!(request.executable_at, None);
+
+    // Once approved, the request must be executed within 7 days of becoming executable.
+    client.approve_recovery(&owner, &guardians.get_unchecked(0), &new_admin);
+    let executable_at = client.get_recoveries(&owner).get_unchecked(0).executable_at.unwrap();
+    env.ledger()
+        .with_mut(|li| li.timestamp = executable_at + 7 * 86_400 + 1);
+    assert_eq!(
+        client.try_execute_recovery(&owner).err(),
+        Some(Ok(ContractError::RecoveryExpired.into()))
+    );
+}
+
+#[test]
+fn test_vault_admin_cancels_recovery() {
+    let (env, admin, _issuer, _contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let owner = Address::generate(&env);
+    client.create_vault(
+        &owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"),
+    );
+    let guardian = Address::generate(&env);
+    client.set_guardians(&owner, &vec![&env, guardian.clone()], &1, &86_400);
+    client.approve_recovery(&owner, &guardian, &Address::generate(&env));
+
+    client.cancel_recovery(&owner);
+    assert!(client.get_recoveries(&owner).is_empty());
+    assert!(client.try_execute_recovery(&owner).is_err());
+}
+
+#[test]
+#[should_panic]
+fn test_recovery_approval_by_non_guardian_panics() {
+    let (env, admin, _issuer, _contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let owner = Address::generate(&env);
+    client.create_vault(
+        &owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"),
+    );
+    client.set_guardians(&owner, &vec![&env, Address::generate(&env)], &1, &86_400);
+    client.approve_recovery(&owner, &Address::generate(&env), &Address::generate(&env));
+}
+
+#[test]
+fn test_rogue_guardian_cannot_block_recovery() {
+    let (env, admin, _issuer, _contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let owner = Address::generate(&env);
+    client.create_vault(
+        &owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"),
+    );
+    let guardians = vec![
+        &env,
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    client.set_guardians(&owner, &guardians, &2, &86_400);
+    let (rogue, honest_a, honest_b) = (
+        guardians.get_unchecked(0),
+        guardians.get_unchecked(1),
+        guardians.get_unchecked(2),
+    );
+
+    // The rogue guardian opens a request for its own key first.
+    client.approve_recovery(&owner, &rogue, &rogue);
+    let new_admin = Address::generate(&env);
+    client.approve_recovery(&owner, &honest_a, &new_admin);
+    assert_eq!(client.get_recoveries(&owner).len(), 2);
+
+    // Reaching the threshold drops the competing request, which can no longer be approved.
+    client.approve_recovery(&owner, &honest_b, &new_admin);
+    let requests = client.get_recoveries(&owner);
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests.get_unchecked(0).new_admin, new_admin);
+    assert_eq!(
+        client.try_approve_recovery(&owner, &rogue, &rogue).err(),
+        Some(Ok(ContractError::RecoveryAlreadyPending.into()))
+    );
+
+    let executable_at = requests.get_unchecked(0).executable_at.unwrap();
+    env.ledger().with_mut(|li| li.timestamp = executable_at);
+    client.execute_recovery(&owner);
+    client.authorize_issuer(&owner, &Address::generate(&env), &None, &None, &vec![&env]);
+    let signers: std::vec::Vec<Address> = env.auths().into_iter().map(|(addr, _)| addr).collect();
+    assert_eq!(signers, [new_admin]);
+}
+
+#[test]
+fn test_guardian_approval_moves_to_latest_candidate() {
+    let (env, admin, _issuer, _contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let owner = Address::generate(&env);
+    client.create_vault(
+        &owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"),
+    );
+    let guardians = vec![&env, Address::generate(&env), Address::generate(&env)];
+    client.set_guardians(&owner, &guardians, &2, &86_400);
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    client.approve_recovery(&owner, &guardians.get_unchecked(0), &first);
+    client.approve_recovery(&owner, &guardians.get_unchecked(0), &second);
+    let requests = client.get_recoveries(&owner);
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests.get_unchecked(0).new_admin, second);
+}
+
+#[test]
+#[should_panic]
+fn test_set_guardians_threshold_above_count_panics() {
+    let (env, admin, _issuer, _contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let owner = Address::generate(&env);
+    client.create_vault(
+        &owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"),
+    );
+    client.set_guardians(&owner, &vec![&env, Address::generate(&env)], &2, &86_400);
+}
+
+/// Propose `action` as `signer` and execute it right away (enough under threshold 1).
+fn run_admin_action(client: &VcVaultContractClient, signer: &Address, action: AdminAction) {
+    let id = client.propose_admin_action(signer, &action);
+    client.execute_admin_action(&id);
+}
+
+#[test]
+fn test_admin_signer_set_two_of_three() {
+    let (env, admin, _issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    assert_eq!(client.get_admin_signers(), vec![&env, admin.clone()]);
+    let second = Address::generate(&env);
+    let third = Address::generate(&env);
+    run_admin_action(&client, &admin, AdminAction::AddSigner(second.clone()));
+    run_admin_action(&client, &admin, AdminAction::AddSigner(third.clone()));
+    run_admin_action(&client, &admin, AdminAction::SetThreshold(2));
+    let (topics, _) = last_event(&env, &contract_id);
+    assert_eq!(
+        topics,
+        (symbol_short!("contract"), symbol_short!("executed"), 3_u32).into_val(&env)
+    );
+    assert_eq!(
+        client.get_admin_signers(),
+        vec![&env, admin.clone(), second.clone(), third.clone()]
+    );
+    assert_eq!(client.get_admin_threshold(), 2);
+
+    // No single signer can act alone any more.
+    assert!(client.try_set_fee_enabled(&true).is_err());
+
+    let id = client.propose_admin_action(&second, &AdminAction::SetFeeEnabled(true));
+    assert!(client.try_execute_admin_action(&id).is_err());
+    client.approve_admin_action(&third, &id);
+    client.execute_admin_action(&id);
+    assert!(client.fee_config().enabled);
+    assert_eq!(client.get_admin_proposal(&id), None);
+    assert!(client.try_execute_admin_action(&id).is_err());
+}
+
+#[test]
+fn test_removed_signer_approval_does_not_count() {
+    let (env, admin, _issuer, _contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let second = Address::generate(&env);
+    let third = Address::generate(&env);
+    run_admin_action(&client, &admin, AdminAction::AddSigner(second.clone()));
+    run_admin_action(&client, &admin, AdminAction::AddSigner(third.clone()));
+    run_admin_action(&client, &admin, AdminAction::SetThreshold(2));
+
+    let pending = client.propose_admin_action(&second, &AdminAction::SetReactivationCooldown(60));
+    client.approve_admin_action(&admin, &pending);
+    let removal = client.propose_admin_action(&admin, &AdminAction::RemoveSigner(admin.clone()));
+    client.approve_admin_action(&third, &removal);
+    client.execute_admin_action(&removal);
+
+    // `admin`'s approval of the pending proposal lapsed with its removal.
+    assert!(client.try_execute_admin_action(&pending).is_err());
+    client.approve_admin_action(&third, &pending);
+    client.execute_admin_action(&pending);
+    assert_eq!(client.get_reactivation_cooldown(), 60);
+}
+
+#[test]
+fn test_admin_proposal_expires() {
+    let (env, admin, _issuer, _contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let id = client.propose_admin_action(&admin, &AdminAction::SetFeeStandard(5));
+    let expires_at = client.get_admin_proposal(&id).unwrap().expires_at;
+    assert_eq!(expires_at, 1_000 + 7 * 24 * 60 * 60);
+    env.ledger().with_mut(|li| li.timestamp = expires_at + 1);
+    assert!(client.try_execute_admin_action(&id).is_err());
+}
+
+#[test]
+#[should_panic]
+fn test_propose_admin_action_by_non_signer_panics() {
+    let (env, admin, _issuer, _contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    client.propose_admin_action(&Address::generate(&env), &AdminAction::SetFeeEnabled(true));
+}
+
+#[test]
+#[should_panic]
+fn test_admin_threshold_above_signer_count_panics() {
+    let (env, admin, _issuer, _contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    run_admin_action(&client, &admin, AdminAction::SetThreshold(2));
+}
+
+#[test]
+fn test_upgrade_is_timelocked() {
+    let (env, admin, _issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    assert_eq!(client.get_upgrade_delay(), 24 * 60 * 60);
+    client.set_upgrade_delay(&(2 * 24 * 60 * 60));
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let wasm_hash = BytesN::from_array(&env, &[7; 32]);
+    client.propose_upgrade(&wasm_hash);
+    let (topics, data) = last_event(&env, &contract_id);
+    assert_eq!(
+        topics,
+        (symbol_short!("contract"), symbol_short!("upg_prop")).into_val(&env)
+    );
+    let executable_at = 1_000 + 2 * 24 * 60 * 60;
+    let data: (BytesN<32>, u64) = data.into_val(&env);
+    assert_eq!(data, (wasm_hash.clone(), executable_at));
+
+    let pending = client.get_pending_upgrade().unwrap();
+    assert_eq!(pending.wasm_hash, wasm_hash);
+    assert_eq!(pending.proposed_at, 1_000);
+    assert_eq!(pending.executable_at, executable_at);
+
+    env.ledger().with_mut(|li| li.timestamp = executable_at - 1);
+    assert!(client.try_execute_upgrade().is_err());
+    // A second announcement must wait for the first to be cancelled.
+    assert!(client
+        .try_propose_upgrade(&BytesN::from_array(&env, &[8; 32]))
+        .is_err());
+}
+
+#[test]
+fn test_cancel_upgrade() {
+    let (env, admin, _issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let wasm_hash = BytesN::from_array(&env, &[7; 32]);
+    client.propose_upgrade(&wasm_hash);
+    client.cancel_upgrade();
+    let (topics, _) = last_event(&env, &contract_id);
+    assert_eq!(
+        topics,
+        (symbol_short!("contract"), symbol_short!("upg_cncl")).into_val(&env)
+    );
+    assert_eq!(client.get_pending_upgrade(), None);
+
+    env.ledger().with_mut(|li| li.timestamp = 24 * 60 * 60);
+    assert!(client.try_execute_upgrade().is_err());
+    assert!(client.try_cancel_upgrade().is_err());
+}
+
+#[test]
+fn test_upgrade_via_admin_proposal() {
+    let (env, admin, _issuer, _contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let second = Address::generate(&env);
+    run_admin_action(&client, &admin, AdminAction::AddSigner(second.clone()));
+    run_admin_action(&client, &admin, AdminAction::SetThreshold(2));
+    let wasm_hash = BytesN::from_array(&env, &[7; 32]);
+    assert!(client.try_propose_upgrade(&wasm_hash).is_err());
+
+    let id = client.propose_admin_action(&admin, &AdminAction::ProposeUpgrade(wasm_hash.clone()));
+    client.approve_admin_action(&second, &id);
+    client.execute_admin_action(&id);
+    assert_eq!(client.get_pending_upgrade().unwrap().wasm_hash, wasm_hash);
+}
+
+#[test]
+#[should_panic]
+fn test_upgrade_delay_below_minimum_panics() {
+    let (env, admin, _issuer, _contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    client.set_upgrade_delay(&60);
+}
+
+#[test]
+fn test_pause_guardian_stops_issuance_and_transfers() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let owner = issue_one(&env, &client, &contract_id, &issuer, "vc-1");
+    let to_owner = Address::generate(&env);
+    client.create_vault(
+        &to_owner,
+        &String::from_str(&env, "did:pkh:stellar:testnet:TO"),
+    );
+    let guardian = Address::generate(&env);
+    client.set_pause_guardian(&Some(guardian.clone()));
+    assert_eq!(client.get_pause_guardian(), Some(guardian.clone()));
+
+    let scopes = vec![&env, PauseScope::Issuance, PauseScope::Transfers];
+    client.pause(&guardian, &scopes);
+    let (topics, _) = last_event(&env, &contract_id);
+    assert_eq!(
+        topics,
+        (symbol_short!("contract"), symbol_short!("paused")).into_val(&env)
+    );
+    assert!(client.is_paused(&PauseScope::Issuance));
+    assert!(!client.is_paused(&PauseScope::Revocation));
+
+    let vc_id = String::from_str(&env, "vc-2");
+    let result = client.try_issue(
+        &owner,
+        &vc_id,
+        &String::from_str(&env, "<ciphertext>"),
+        &contract_id,
+        &issuer,
+        &String::from_str(&env, "did:pkh:stellar:testnet:ISSUER"),
+        &None,
+        &None,
+        &true,
+    );
+    assert_eq!(result.err(), Some(Ok(ContractError::Paused.into())));
+    let vc_1 = String::from_str(&env, "vc-1");
+    let result = client.try_push(&owner, &to_owner, &vc_1);
+    assert_eq!(result.err(), Some(Ok(ContractError::Paused.into())));
+
+    // Reads and unpaused scopes keep working.
+    assert!(client.get_vc(&owner, &vc_1).is_some());
+    let status = client.verify_vc(&owner, &vc_1);
+    assert_eq!(
+        status.get(String::from_str(&env, "status")).unwrap(),
+        String::from_str(&env, "valid")
+    );
+    client.create_vault(
+        &Address::generate(&env),
+        &String::from_str(&env, "did:pkh:stellar:testnet:NEW"),
+    );
+
+    client.unpause(&scopes);
+    let (topics, _) = last_event(&env, &contract_id);
+    assert_eq!(
+        topics,
+        (symbol_short!("contract"), symbol_short!("unpaused")).into_val(&env)
+    );
+    client.push(&owner, &to_owner, &vc_1);
+}
+
+#[test]
+fn test_pause_revocation_and_vault_creation() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    issue_one(&env, &client, &contract_id, &issuer, "vc-1");
+    client.pause(
+        &admin,
+        &vec![&env, PauseScope::Revocation, PauseScope::VaultCreation],
+    );
+
+    let vc_id = String::from_str(&env, "vc-1");
+    let result = client.try_revoke(
+        &issuer,
+        &issuer,
+        &vc_id,
+        &RevocationReason::Unspecified,
+        &None,
+    );
+    assert_eq!(result.err(), Some(Ok(ContractError::Paused.into())));
+    assert!(client.try_suspend(&issuer, &issuer, &vc_id, &None).is_err());
+    let result = client.try_create_vault(
+        &Address::generate(&env),
+        &String::from_str(&env, "did:pkh:stellar:testnet:NEW"),
+    );
+    assert_eq!(result.err(), Some(Ok(ContractError::Paused.into())));
+
+    client.unpause(&vec![&env, PauseScope::Revocation]);
+    client.revoke(
+        &issuer,
+        &issuer,
+        &vc_id,
+        &RevocationReason::Unspecified,
+        &None,
+    );
+    assert!(client.is_paused(&PauseScope::VaultCreation));
+}
+
+#[test]
+#[should_panic]
+fn test_pause_by_non_guardian_panics() {
+    let (env, admin, _issuer, _contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    client.set_pause_guardian(&Some(Address::generate(&env)));
+    client.pause(&Address::generate(&env), &vec![&env, PauseScope::Issuance]);
+}
+
+#[test]
+fn test_roles_split_admin_duties() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let fee_manager = Address::generate(&env);
+    let curator = Address::generate(&env);
+    client.grant_role(&Role::FeeManager, &fee_manager);
+    let (topics, _) = last_event(&env, &contract_id);
+    assert_eq!(
+        topics,
+        (symbol_short!("contract"), symbol_short!("role_gnt")).into_val(&env)
+    );
+    client.grant_role(&Role::IssuerCurator, &curator);
+    assert!(client.has_role(&Role::FeeManager, &fee_manager));
+    assert!(!client.has_role(&Role::Upgrader, &fee_manager));
+
+    client.execute_role_action(&fee_manager, &AdminAction::SetFeeStandard(2_000_000));
+    let signers: std::vec::Vec<Address> = env.auths().into_iter().map(|(addr, _)| addr).collect();
+    assert_eq!(signers.len(), 1);
+    assert_eq!(signers[0], fee_manager);
+    assert_eq!(client.get_fee_standard(), 2_000_000);
+    client.execute_role_action(
+        &curator,
+        &AdminAction::SetIssuerTier(issuer.clone(), FeeTier::Early),
+    );
+    assert_eq!(client.get_issuer_tier(&issuer), FeeTier::Early);
+
+    // Each role is limited to its own actions; admin-only actions need the admin.
+    let result = client.try_execute_role_action(
+        &fee_manager,
+        &AdminAction::SetIssuerTier(issuer.clone(), FeeTier::Standard),
+    );
+    assert_eq!(result.err(), Some(Ok(ContractError::MissingRole.into())));
+    assert!(client
+        .try_execute_role_action(&curator, &AdminAction::SetFeeEnabled(true))
+        .is_err());
+    assert!(client
+        .try_execute_role_action(
+            &fee_manager,
+            &AdminAction::GrantRole(Role::FeeManager, issuer.clone())
+        )
+        .is_err());
+
+    client.revoke_role(&Role::FeeManager, &fee_manager);
+    assert!(!client.has_role(&Role::FeeManager, &fee_manager));
+    assert!(client
+        .try_execute_role_action(&fee_manager, &AdminAction::SetFeeStandard(1))
+        .is_err());
+}
+
+#[test]
+fn test_renounce_role() {
+    let (env, admin, _issuer, contract_id, client) = setup();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "did:acta:default"),
+        &deploy_salt(&env),
+    );
+    let pauser = Address::generate(&env);
+    client.grant_role(&Role::Pauser, &pauser);
+    client.pause(&pauser, &vec![&env, PauseScope::Issuance]);
+    client.execute_role_action(
+        &pauser,
+        &AdminAction::Unpause(vec![&env, PauseScope::Issuance]),
+    );
+    assert!(!client.is_paused(&PauseScope::Issuance));
+
+    client.renounce_role(&pauser, &Role::Pauser);
+    let signers: std::vec::Vec<Address> = env.auths().into_iter().map(|(addr, _)| addr).collect();
+    assert_eq!(signers.len(), 1);
+    assert_eq!(signers[0], pauser);
+    let (topics, _) = last_event(&env, &contract_id);
+    assert_eq!(
+        topics,
+        (symbol_short!("contract"), symbol_short!("role_rvk")).into_val(&env)
+    );
+    assert!(!client.has_role(&Role::Pauser, &pauser));
+    assert!(client
+        .try_pause(&pauser, &vec![&env, PauseScope::Issuance])
+        .is_err());
+    assert!(client.try_renounce_role(&pauser, &Role::Pauser).is_err());
+}
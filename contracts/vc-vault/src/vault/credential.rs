@@ -1,24 +1,117 @@
 //! Store VC payload in vault and update index.
 
-use crate::model::VerifiableCredential;
+use crate::error::ContractError;
+use crate::model::{VCTombstone, VerifiableCredential};
 use crate::storage;
-use soroban_sdk::{Address, Env, String};
+use crate::vault::{inbox, quota};
+use soroban_sdk::{panic_with_error, Address, Env, String, Vec};
 
-/// Write VC to vault and append ID to index.
+/// Write VC to vault and append ID to index, or hold it in the inbox when enabled.
+/// Returns the acceptance deadline if it is pending.
 pub fn store_vc(
     e: &Env,
     owner: &Address,
     id: String,
     data: String,
     issuance_contract: Address,
+    issuer: Address,
     issuer_did: String,
-) {
+    valid_from: Option<u64>,
+    valid_until: Option<u64>,
+    transferable: bool,
+) -> Option<u64> {
     let new_vc = VerifiableCredential {
-        id: id.clone(),
+        id,
         data,
         issuance_contract,
+        issuer,
         issuer_did,
+        valid_from,
+        valid_until,
+        transferable,
     };
-    storage::write_vault_vc(e, owner, &id, &new_vc);
-    storage::append_vault_vc_id(e, owner, &id);
+    inbox::deliver(e, owner, &new_vc, None)
+}
+
+/// Write an existing VC into the vault, index it and count it against its issuer.
+/// Points the status registry's owner entry at this vault when the VC is registered here.
+pub fn insert_vc(e: &Env, owner: &Address, vc: &VerifiableCredential) {
+    migrate_legacy_index(e, owner);
+    storage::write_vault_vc(e, owner, &vc.id, vc);
+    storage::append_vault_vc_id(e, owner, &vc.id);
+    quota::record_added(e, owner, &vc.issuer);
+    if is_registered(e, vc) {
+        storage::write_vc_owner(e, &vc.issuer, &vc.id, owner);
+    }
+}
+
+/// Whether this contract's status registry has an entry for `vc` under its issuer.
+pub fn is_registered(e: &Env, vc: &VerifiableCredential) -> bool {
+    vc.issuance_contract == e.current_contract_address()
+        && storage::has_vc_status(e, &vc.issuer, &vc.id)
+}
+
+/// Drop VC payload and index entry from the vault and release its issuer's quota.
+pub fn remove_vc(e: &Env, owner: &Address, vc: &VerifiableCredential) {
+    migrate_legacy_index(e, owner);
+    storage::remove_vault_vc(e, owner, &vc.id);
+    storage::remove_vault_vc_id(e, owner, &vc.id);
+    quota::record_removed(e, owner, &vc.issuer);
+}
+
+/// Holder-initiated removal. Leaves the status registry alone; with `tombstone` a payload-free
+/// marker keeps the ID from re-entering the vault. Panics if the VC is not in the vault.
+pub fn delete_vc(
+    e: &Env,
+    owner: &Address,
+    vc_id: &String,
+    tombstone: bool,
+) -> VerifiableCredential {
+    let vc = match storage::read_vault_vc(e, owner, vc_id) {
+        Some(vc) => vc,
+        None => panic_with_error!(e, ContractError::VCNotFound),
+    };
+    remove_vc(e, owner, &vc);
+    if tombstone {
+        storage::write_vc_tombstone(
+            e,
+            owner,
+            &VCTombstone {
+                id: vc.id.clone(),
+                issuer: vc.issuer.clone(),
+                issuance_contract: vc.issuance_contract.clone(),
+                deleted_at: e.ledger().timestamp(),
+            },
+        );
+    }
+    vc
+}
+
+/// The pre-paging index (newest first), if the vault still has one. Until the first write
+/// migrates it, the paged index is empty and reads go to this vector instead.
+pub fn legacy_index(e: &Env, owner: &Address) -> Option<Vec<String>> {
+    storage::read_legacy_vault_vc_ids(e, owner)
+}
+
+/// Number of IDs in the vault index, legacy or paged.
+pub fn vc_count(e: &Env, owner: &Address) -> u32 {
+    match legacy_index(e, owner) {
+        Some(ids) => ids.len(),
+        None => storage::read_vault_vc_count(e, owner),
+    }
+}
+
+/// Move a pre-paging index into the paged one, oldest first. IDs whose payload is gone are
+/// dropped. Returns how many IDs were moved, or None if there was no legacy index.
+pub fn migrate_legacy_index(e: &Env, owner: &Address) -> Option<u32> {
+    let ids = legacy_index(e, owner)?;
+    let mut moved = 0;
+    for vc_id in ids.iter().rev() {
+        if storage::has_vault_vc(e, owner, &vc_id) {
+            storage::append_vault_vc_id(e, owner, &vc_id);
+            moved += 1;
+        }
+    }
+    storage::remove_legacy_vault_vc_ids(e, owner);
+    Some(moved)
 }
@@ -0,0 +1,34 @@
+//! Two-step vault admin handover: the vault admin proposes, the new admin accepts.
+
+use crate::error::ContractError;
+use crate::model::AdminHandover;
+use crate::storage;
+use soroban_sdk::{panic_with_error, Address, Env};
+
+/// Seconds a proposed handover stays acceptable (7 days).
+pub const HANDOVER_LIFETIME: u64 = 7 * 24 * 60 * 60;
+
+/// Record `new_admin` as owner's proposed vault admin, replacing any earlier proposal.
+pub fn propose_admin(e: &Env, owner: &Address, new_admin: &Address) -> AdminHandover {
+    let handover = AdminHandover {
+        new_admin: new_admin.clone(),
+        expires_at: e.ledger().timestamp().saturating_add(HANDOVER_LIFETIME),
+    };
+    storage::write_pending_vault_admin(e, owner, Some(&handover));
+    handover
+}
+
+/// Install the proposed vault admin. The new admin must sign.
+pub fn accept_admin(e: &Env, owner: &Address) -> Address {
+    let handover = match storage::read_pending_vault_admin(e, owner) {
+        Some(handover) => handover,
+        None => panic_with_error!(e, ContractError::NoHandoverPending),
+    };
+    if e.ledger().timestamp() > handover.expires_at {
+        panic_with_error!(e, ContractError::HandoverExpired)
+    }
+    handover.new_admin.require_auth();
+    storage::write_pending_vault_admin(e, owner, None);
+    storage::write_vault_admin(e, owner, &handover.new_admin);
+    handover.new_admin
+}
@@ -0,0 +1,117 @@
+//! Pending inbox: when enabled, incoming credentials wait for the holder to accept or reject.
+
+use crate::error::ContractError;
+use crate::model::{PendingPage, PendingVC, VerifiableCredential};
+use crate::storage::{self, INDEX_PAGE_SIZE};
+use crate::vault::listing::MAX_PAGE_LIMIT;
+use crate::vault::{credential, quota};
+use soroban_sdk::{panic_with_error, Address, Env, String, Vec};
+
+/// Store `vc` in owner's vault, or in the inbox when enabled.
+/// Returns the acceptance deadline if it is pending. A pending VC has no registry owner: no
+/// vault holds it until it is accepted.
+pub fn deliver(
+    e: &Env,
+    owner: &Address,
+    vc: &VerifiableCredential,
+    sender: Option<Address>,
+) -> Option<u64> {
+    match storage::read_inbox_ttl(e, owner) {
+        Some(ttl) => {
+            let now = e.ledger().timestamp();
+            let pending = PendingVC {
+                vc: vc.clone(),
+                sender,
+                received_at: now,
+                expires_at: now.saturating_add(ttl),
+            };
+            storage::write_pending_vc(e, owner, &pending);
+            quota::record_added(e, owner, &vc.issuer);
+            if credential::is_registered(e, vc) {
+                storage::remove_vc_owner(e, &vc.issuer, &vc.id);
+            }
+            Some(pending.expires_at)
+        }
+        None => {
+            credential::insert_vc(e, owner, vc);
+            None
+        }
+    }
+}
+
+/// Move a pending credential into the vault. Panics if missing or expired, or if the issuer's
+/// quota or the vault cap no longer has room for it.
+pub fn accept_vc(e: &Env, owner: &Address, vc_id: &String) -> VerifiableCredential {
+    let pending = read_pending(e, owner, vc_id);
+    if e.ledger().timestamp() > pending.expires_at {
+        panic_with_error!(e, ContractError::PendingVCExpired)
+    }
+    if storage::has_vault_vc(e, owner, vc_id) {
+        panic_with_error!(e, ContractError::VCAlreadyExists)
+    }
+    storage::remove_pending_vc(e, owner, vc_id);
+    quota::record_removed(e, owner, &pending.vc.issuer);
+    quota::check_quota(e, owner, &pending.vc.issuer);
+    credential::insert_vc(e, owner, &pending.vc);
+    pending.vc
+}
+
+/// Drop a pending credential, expired or not. A pushed credential goes back to its sender's
+/// vault when the sender no longer holds that ID. Returns the sender it was returned to.
+/// A freshly issued credential was never held, so its status registry entry is removed and the
+/// issuer may issue the ID again.
+pub fn reject_vc(e: &Env, owner: &Address, vc_id: &String) -> Option<Address> {
+    let pending = read_pending(e, owner, vc_id);
+    storage::remove_pending_vc(e, owner, vc_id);
+    quota::record_removed(e, owner, &pending.vc.issuer);
+    match pending.sender {
+        Some(sender)
+            if storage::has_vault_admin(e, &sender)
+                && !storage::has_vault_vc(e, &sender, vc_id) =>
+        {
+            credential::insert_vc(e, &sender, &pending.vc);
+            Some(sender)
+        }
+        Some(_) => None,
+        None => {
+            if credential::is_registered(e, &pending.vc) {
+                storage::remove_vc_status(e, &pending.vc.issuer, vc_id);
+            }
+            None
+        }
+    }
+}
+
+/// Walk the inbox index from `cursor`, returning up to `limit` pending credentials.
+pub fn list_pending(e: &Env, owner: &Address, cursor: u32, limit: u32) -> PendingPage {
+    if limit == 0 {
+        panic_with_error!(e, ContractError::InvalidPageLimit)
+    }
+    let limit = limit.min(MAX_PAGE_LIMIT);
+    let count = storage::read_pending_count(e, owner);
+    let mut items = Vec::new(e);
+    let mut pos = cursor;
+    let mut page_no = u32::MAX;
+    let mut page: Vec<String> = Vec::new(e);
+    while pos < count && items.len() < limit {
+        if pos / INDEX_PAGE_SIZE != page_no {
+            page_no = pos / INDEX_PAGE_SIZE;
+            page = storage::read_pending_page(e, owner, page_no);
+            storage::extend_pending_page_ttl(e, owner, page_no);
+        }
+        let vc_id = page.get_unchecked(pos % INDEX_PAGE_SIZE);
+        if let Some(pending) = storage::read_pending_vc(e, owner, &vc_id) {
+            items.push_back(pending);
+        }
+        pos += 1;
+    }
+    let next_cursor = if pos < count { Some(pos) } else { None };
+    PendingPage { items, next_cursor }
+}
+
+fn read_pending(e: &Env, owner: &Address, vc_id: &String) -> PendingVC {
+    match storage::read_pending_vc(e, owner, vc_id) {
+        Some(pending) => pending,
+        None => panic_with_error!(e, ContractError::VCNotFound),
+    }
+}
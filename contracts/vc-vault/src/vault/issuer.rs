@@ -1,36 +1,144 @@
-//! Issuer list management: add, remove, replace authorized issuers per vault.
+//! Issuer authorization management: one entry per (vault, issuer) with metadata.
 
 use crate::error::ContractError;
-use crate::storage;
-use soroban_sdk::{panic_with_error, Address, Env, Vec};
+use crate::model::{IssuerAuthorization, IssuerPage};
+use crate::storage::{self, INDEX_PAGE_SIZE};
+use crate::vault::listing::MAX_PAGE_LIMIT;
+use soroban_sdk::{panic_with_error, Address, Env, String, Vec};
 
-/// Add single issuer to vault. Panics if already authorized.
-pub fn authorize_issuer(e: &Env, owner: &Address, issuer: &Address) {
-    let mut issuers: Vec<Address> = storage::read_vault_issuers(e, owner);
-    if is_authorized(&issuers, issuer) {
-        panic_with_error!(e, ContractError::IssuerAlreadyAuthorized)
+/// Add single issuer to vault, optionally lapsing after `expires_at`.
+/// Panics if already authorized; a lapsed authorization may be granted again.
+pub fn authorize_issuer(
+    e: &Env,
+    owner: &Address,
+    issuer: &Address,
+    expires_at: Option<u64>,
+    label: Option<String>,
+    credential_types: Vec<String>,
+) {
+    if let Some(expires_at) = expires_at {
+        if expires_at < e.ledger().timestamp() {
+            panic_with_error!(e, ContractError::IssuerAuthorizationExpired)
+        }
     }
-    issuers.push_front(issuer.clone());
-    storage::write_vault_issuers(e, owner, &issuers);
+    migrate_legacy_issuers(e, owner);
+    if let Some(auth) = storage::read_vault_issuer(e, owner, issuer) {
+        if !is_expired(e, &auth) {
+            panic_with_error!(e, ContractError::IssuerAlreadyAuthorized)
+        }
+    }
+    write_authorization(e, owner, issuer, expires_at, label, credential_types);
 }
 
-/// Replace full issuer list for vault.
-pub fn authorize_issuers(e: &Env, owner: &Address, issuers: &Vec<Address>) {
-    storage::write_vault_issuers(e, owner, issuers);
+/// Add several issuers without metadata. Issuers with an unexpired authorization keep their
+/// entry; lapsed ones are authorized again, open-ended.
+pub fn add_issuers(e: &Env, owner: &Address, issuers: &Vec<Address>) {
+    migrate_legacy_issuers(e, owner);
+    for issuer in issuers.iter() {
+        match storage::read_vault_issuer(e, owner, &issuer) {
+            Some(auth) if !is_expired(e, &auth) => {}
+            _ => write_authorization(e, owner, &issuer, None, None, Vec::new(e)),
+        }
+    }
 }
 
 /// Remove issuer from vault. Panics if not authorized.
 pub fn revoke_issuer(e: &Env, owner: &Address, issuer: &Address) {
-    let mut issuers = storage::read_vault_issuers(e, owner);
-    if let Some(issuer_index) = issuers.first_index_of(issuer) {
-        issuers.remove(issuer_index);
-    } else {
+    migrate_legacy_issuers(e, owner);
+    if !storage::has_vault_issuer(e, owner, issuer) {
         panic_with_error!(e, ContractError::IssuerNotAuthorized)
     }
-    storage::write_vault_issuers(e, owner, &issuers);
+    storage::remove_vault_issuer(e, owner, issuer);
+}
+
+/// Panic unless issuer has an unexpired authorization in the vault. Until the first issuer
+/// change migrates it, a legacy issuer vector still authorizes the issuers it lists.
+pub fn require_authorized(e: &Env, owner: &Address, issuer: &Address) {
+    match storage::read_vault_issuer(e, owner, issuer) {
+        None if storage::read_legacy_vault_issuers(e, owner)
+            .is_some_and(|issuers| issuers.contains(issuer)) => {}
+        None => panic_with_error!(e, ContractError::IssuerNotAuthorized),
+        Some(auth) if is_expired(e, &auth) => {
+            panic_with_error!(e, ContractError::IssuerAuthorizationExpired)
+        }
+        Some(_) => {}
+    }
+}
+
+/// True once the ledger time is past `expires_at`. Open-ended authorizations never expire.
+pub fn is_expired(e: &Env, auth: &IssuerAuthorization) -> bool {
+    match auth.expires_at {
+        Some(expires_at) => e.ledger().timestamp() > expires_at,
+        None => false,
+    }
+}
+
+/// Walk the issuer index from `cursor`, returning up to `limit` authorizations.
+pub fn list_issuers(e: &Env, owner: &Address, cursor: u32, limit: u32) -> IssuerPage {
+    if limit == 0 {
+        panic_with_error!(e, ContractError::InvalidPageLimit)
+    }
+    let limit = limit.min(MAX_PAGE_LIMIT);
+    let count = storage::read_vault_issuer_count(e, owner);
+    let mut issuers = Vec::new(e);
+    let mut pos = cursor;
+    let mut page_no = u32::MAX;
+    let mut page: Vec<Address> = Vec::new(e);
+    while pos < count && issuers.len() < limit {
+        if pos / INDEX_PAGE_SIZE != page_no {
+            page_no = pos / INDEX_PAGE_SIZE;
+            page = storage::read_vault_issuer_page(e, owner, page_no);
+            storage::extend_vault_issuer_page_ttl(e, owner, page_no);
+        }
+        let issuer = page.get_unchecked(pos % INDEX_PAGE_SIZE);
+        if let Some(auth) = storage::read_vault_issuer(e, owner, &issuer) {
+            issuers.push_back(auth);
+        }
+        pos += 1;
+    }
+    let next_cursor = if pos < count { Some(pos) } else { None };
+    IssuerPage {
+        issuers,
+        next_cursor,
+    }
+}
+
+/// Move a pre-entry issuer vector into per-issuer entries. Returns number migrated.
+pub fn migrate_issuers(e: &Env, owner: &Address) -> u32 {
+    match migrate_legacy_issuers(e, owner) {
+        Some(count) => count,
+        None => panic_with_error!(e, ContractError::VCSAlreadyMigrated),
+    }
+}
+
+/// Same as `migrate_issuers`, but a no-op (None) when there is no legacy vector. Issuer
+/// changes call it first so they never mix with an unmigrated list.
+fn migrate_legacy_issuers(e: &Env, owner: &Address) -> Option<u32> {
+    let issuers = storage::read_legacy_vault_issuers(e, owner)?;
+    // Legacy list is newest-first; append oldest-first.
+    for issuer in issuers.iter().rev() {
+        if !storage::has_vault_issuer(e, owner, &issuer) {
+            write_authorization(e, owner, &issuer, None, None, Vec::new(e));
+        }
+    }
+    storage::remove_legacy_vault_issuers(e, owner);
+    Some(issuers.len())
 }
 
-/// Check if issuer is in the list.
-pub fn is_authorized(issuers: &Vec<Address>, issuer: &Address) -> bool {
-    issuers.contains(issuer.clone())
+fn write_authorization(
+    e: &Env,
+    owner: &Address,
+    issuer: &Address,
+    expires_at: Option<u64>,
+    label: Option<String>,
+    credential_types: Vec<String>,
+) {
+    let auth = IssuerAuthorization {
+        issuer: issuer.clone(),
+        authorized_at: e.ledger().timestamp(),
+        expires_at,
+        label,
+        credential_types,
+    };
+    storage::write_vault_issuer(e, owner, &auth);
 }
@@ -0,0 +1,59 @@
+//! Vault reactivation and closure.
+
+use crate::error::ContractError;
+use crate::storage;
+use crate::vault::{credential, inbox};
+use soroban_sdk::{panic_with_error, Address, Env};
+
+/// Max entries removed per `close_vault` call, so each call stays within ledger write limits.
+pub const MAX_CLOSE_BATCH: u32 = 50;
+
+/// Unfreeze a revoked vault once the contract's reactivation cooldown has passed.
+pub fn reactivate(e: &Env, owner: &Address) {
+    if !storage::read_vault_revoked(e, owner) {
+        panic_with_error!(e, ContractError::VaultNotRevoked)
+    }
+    let ready_at = storage::read_vault_revoked_at(e, owner)
+        .saturating_add(storage::read_reactivation_cooldown(e));
+    if e.ledger().timestamp() < ready_at {
+        panic_with_error!(e, ContractError::ReactivationCooldown)
+    }
+    storage::write_vault_revoked(e, owner, &false);
+}
+
+/// Remove up to `limit` pending items (pushed ones go back to their sender), credentials and
+/// issuer entries, in that order; once all are gone, remove the vault metadata. Returns how many indexed entries remain (0 = closed).
+/// Legacy single-vector indexes must be migrated first, or the payloads they list would be
+/// orphaned.
+pub fn close_batch(e: &Env, owner: &Address, limit: u32) -> u32 {
+    if limit == 0 {
+        panic_with_error!(e, ContractError::InvalidPageLimit)
+    }
+    if storage::has_legacy_vault_index(e, owner) {
+        panic_with_error!(e, ContractError::LegacyIndexNotMigrated)
+    }
+    let mut budget = limit.min(MAX_CLOSE_BATCH);
+    while budget > 0 {
+        if let Some(vc_id) = storage::last_pending_id(e, owner) {
+            inbox::reject_vc(e, owner, &vc_id);
+        } else if let Some(vc_id) = storage::last_vault_vc_id(e, owner) {
+            match storage::read_vault_vc(e, owner, &vc_id) {
+                Some(vc) => credential::remove_vc(e, owner, &vc),
+                None => storage::remove_vault_vc_id(e, owner, &vc_id),
+            }
+        } else if let Some(issuer) = storage::last_vault_issuer(e, owner) {
+            storage::remove_vault_issuer(e, owner, &issuer);
+            storage::write_issuer_quota(e, owner, &issuer, None);
+        } else {
+            break;
+        }
+        budget -= 1;
+    }
+    let remaining = storage::read_pending_count(e, owner)
+        + storage::read_vault_vc_count(e, owner)
+        + storage::read_vault_issuer_count(e, owner);
+    if remaining == 0 {
+        storage::remove_vault_metadata(e, owner);
+    }
+    remaining
+}
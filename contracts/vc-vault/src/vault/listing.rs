@@ -0,0 +1,90 @@
+//! Paginated, filtered listing of a vault's credentials.
+
+use crate::error::ContractError;
+use crate::issuance;
+use crate::model::{VCFilter, VCPage, VerifiableCredential};
+use crate::storage::{self, INDEX_PAGE_SIZE};
+use crate::vault::credential;
+use soroban_sdk::{panic_with_error, Address, Env, String, Vec};
+
+/// Max VCs returned per page.
+pub const MAX_PAGE_LIMIT: u32 = 50;
+
+/// Max index entries examined per call, so selective filters stay within read limits.
+const MAX_SCAN: u32 = 200;
+
+/// All IDs in the vault, oldest first. A legacy index is read in place until it is migrated.
+pub fn list_vc_ids(e: &Env, owner: &Address) -> Vec<String> {
+    match credential::legacy_index(e, owner) {
+        Some(legacy) => {
+            let mut ids = Vec::new(e);
+            for vc_id in legacy.iter().rev() {
+                ids.push_back(vc_id);
+            }
+            ids
+        }
+        None => storage::read_vault_vc_ids(e, owner),
+    }
+}
+
+/// Walk the vault index from `cursor`, returning up to `limit` VCs that match `filter`.
+/// A legacy index is walked in the same oldest-first order until it is migrated.
+pub fn list_vcs(e: &Env, owner: &Address, cursor: u32, limit: u32, filter: &VCFilter) -> VCPage {
+    if limit == 0 {
+        panic_with_error!(e, ContractError::InvalidPageLimit)
+    }
+    let limit = limit.min(MAX_PAGE_LIMIT);
+    let legacy = credential::legacy_index(e, owner);
+    let count = credential::vc_count(e, owner);
+    let mut vcs = Vec::new(e);
+    let mut pos = cursor;
+    let mut scanned = 0;
+    let mut page_no = u32::MAX;
+    let mut page: Vec<String> = Vec::new(e);
+    while pos < count && vcs.len() < limit && scanned < MAX_SCAN {
+        let vc_id = match &legacy {
+            Some(ids) => ids.get_unchecked(count - 1 - pos),
+            None => {
+                if pos / INDEX_PAGE_SIZE != page_no {
+                    page_no = pos / INDEX_PAGE_SIZE;
+                    page = storage::read_vault_vc_page(e, owner, page_no);
+                    storage::extend_vault_vc_page_ttl(e, owner, page_no);
+                }
+                page.get_unchecked(pos % INDEX_PAGE_SIZE)
+            }
+        };
+        if let Some(vc) = storage::read_vault_vc(e, owner, &vc_id) {
+            if matches(e, &vc, filter) {
+                vcs.push_back(vc);
+            }
+        }
+        pos += 1;
+        scanned += 1;
+    }
+    let next_cursor = if pos < count { Some(pos) } else { None };
+    VCPage { vcs, next_cursor }
+}
+
+fn matches(e: &Env, vc: &VerifiableCredential, filter: &VCFilter) -> bool {
+    if let Some(issuer_did) = &filter.issuer_did {
+        if vc.issuer_did != *issuer_did {
+            return false;
+        }
+    }
+    if let Some(issuance_contract) = &filter.issuance_contract {
+        if vc.issuance_contract != *issuance_contract {
+            return false;
+        }
+    }
+    if let Some(status) = &filter.status {
+        if vc.issuance_contract != e.current_contract_address() {
+            return false;
+        }
+        let stored = storage::read_vc_status(e, &vc.issuer, &vc.id);
+        let effective = issuance::effective_status(e, vc, stored);
+        if String::from_str(e, issuance::status_name(&effective)) != *status {
+            return false;
+        }
+    }
+    true
+}
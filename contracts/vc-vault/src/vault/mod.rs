@@ -1,7 +1,22 @@
-//! Vault: per-owner storage, issuer management, credential storage.
+//! Vault: per-owner storage, lifecycle, issuer management, quotas, inbox, credential storage
+//! and listing.
 
 mod credential;
+mod handover;
+mod inbox;
 mod issuer;
+mod lifecycle;
+mod listing;
+mod quota;
+mod recovery;
 
-pub use credential::store_vc;
-pub use issuer::{authorize_issuer, authorize_issuers, is_authorized, revoke_issuer};
+pub use credential::{delete_vc, insert_vc, migrate_legacy_index, remove_vc, store_vc};
+pub use handover::{accept_admin, propose_admin};
+pub use inbox::{accept_vc, deliver, list_pending, reject_vc};
+pub use issuer::{
+    add_issuers, authorize_issuer, list_issuers, migrate_issuers, require_authorized, revoke_issuer,
+};
+pub use lifecycle::{close_batch, reactivate};
+pub use listing::{list_vc_ids, list_vcs};
+pub use quota::{check_quota, remaining_quota};
+pub use recovery::{approve_recovery, cancel_recovery, execute_recovery, set_guardians};
@@ -0,0 +1,41 @@
+//! Per-issuer credential quotas and the vault-wide cap, enforced on `issue`, `push` and
+//! `accept_vc`. Pending inbox items count like stored ones, since they cost the holder rent too.
+
+use crate::error::ContractError;
+use crate::storage;
+use crate::vault::credential;
+use soroban_sdk::{panic_with_error, Address, Env};
+
+/// Panic if the vault is full or `issuer` has used its quota.
+pub fn check_quota(e: &Env, owner: &Address, issuer: &Address) {
+    if remaining_quota(e, owner, issuer) == Some(0) {
+        panic_with_error!(e, ContractError::VaultQuotaExceeded)
+    }
+}
+
+/// How many more credentials `issuer` may write: the lower of its quota and the vault cap,
+/// both counting pending inbox items. None when neither is set.
+pub fn remaining_quota(e: &Env, owner: &Address, issuer: &Address) -> Option<u32> {
+    let by_issuer = storage::read_issuer_quota(e, owner, issuer)
+        .map(|quota| quota.saturating_sub(storage::read_issuer_usage(e, owner, issuer)));
+    let by_vault = storage::read_vault_cap(e, owner).map(|cap| {
+        let held = credential::vc_count(e, owner) + storage::read_pending_count(e, owner);
+        cap.saturating_sub(held)
+    });
+    match (by_issuer, by_vault) {
+        (Some(a), Some(b)) => Some(a.min(b)),
+        (a, b) => a.or(b),
+    }
+}
+
+/// Count a credential from `issuer` entering the vault or its inbox.
+pub fn record_added(e: &Env, owner: &Address, issuer: &Address) {
+    let usage = storage::read_issuer_usage(e, owner, issuer);
+    storage::write_issuer_usage(e, owner, issuer, usage + 1);
+}
+
+/// Count a credential from `issuer` leaving the vault or its inbox.
+pub fn record_removed(e: &Env, owner: &Address, issuer: &Address) {
+    let usage = storage::read_issuer_usage(e, owner, issuer);
+    storage::write_issuer_usage(e, owner, issuer, usage.saturating_sub(1));
+}
@@ -0,0 +1,142 @@
+//! Guardian-based recovery of a vault admin whose key is lost.
+
+use crate::error::ContractError;
+use crate::model::{GuardianConfig, RecoveryRequest};
+use crate::storage;
+use soroban_sdk::{panic_with_error, Address, Env, Vec};
+
+/// Max guardians per vault; keeps the config a single small entry.
+pub const MAX_GUARDIANS: u32 = 10;
+
+/// Shortest allowed recovery delay (1 day), so the current admin always has time to cancel.
+pub const MIN_RECOVERY_DELAY: u64 = 24 * 60 * 60;
+
+/// Seconds a recovery request stays open, first for approvals and then for execution (7 days).
+pub const RECOVERY_LIFETIME: u64 = 7 * 24 * 60 * 60;
+
+/// Replace the guardian set (empty = none). Drops any pending recovery.
+pub fn set_guardians(
+    e: &Env,
+    owner: &Address,
+    guardians: Vec<Address>,
+    threshold: u32,
+    delay: u64,
+) {
+    storage::write_recoveries(e, owner, &Vec::new(e));
+    if guardians.is_empty() {
+        storage::write_guardians(e, owner, None);
+        return;
+    }
+    if guardians.len() > MAX_GUARDIANS
+        || threshold == 0
+        || threshold > guardians.len()
+        || delay < MIN_RECOVERY_DELAY
+    {
+        panic_with_error!(e, ContractError::InvalidGuardianConfig)
+    }
+    for (i, guardian) in guardians.iter().enumerate() {
+        if guardians.first_index_of(&guardian) != Some(i as u32) || guardian == *owner {
+            panic_with_error!(e, ContractError::InvalidGuardianConfig)
+        }
+    }
+    let config = GuardianConfig {
+        guardians,
+        threshold,
+        delay,
+    };
+    storage::write_guardians(e, owner, Some(&config));
+}
+
+/// Record `guardian`'s approval of rotating the admin to `new_admin`. Requests are kept per
+/// proposed admin and each guardian backs one at a time, so approving a new candidate withdraws
+/// the guardian's earlier approval. The first request to meet the threshold wins: the others are
+/// dropped, its delay starts and it stays executable for `RECOVERY_LIFETIME` after that. Until
+/// then requests lapse `RECOVERY_LIFETIME` after their first approval. Caller must check the
+/// signature.
+pub fn approve_recovery(
+    e: &Env,
+    owner: &Address,
+    guardian: &Address,
+    new_admin: &Address,
+) -> RecoveryRequest {
+    let config = match storage::read_guardians(e, owner) {
+        Some(config) => config,
+        None => panic_with_error!(e, ContractError::NotGuardian),
+    };
+    if !config.guardians.contains(guardian) {
+        panic_with_error!(e, ContractError::NotGuardian)
+    }
+    let now = e.ledger().timestamp();
+    let mut open = Vec::new(e);
+    let mut request = None;
+    for mut pending in storage::read_recoveries(e, owner).iter() {
+        if now > pending.expires_at {
+            continue;
+        }
+        if pending.executable_at.is_some() && pending.new_admin != *new_admin {
+            panic_with_error!(e, ContractError::RecoveryAlreadyPending)
+        }
+        if pending.new_admin == *new_admin {
+            request = Some(pending);
+            continue;
+        }
+        if let Some(i) = pending.approvals.first_index_of(guardian) {
+            pending.approvals.remove(i);
+        }
+        if !pending.approvals.is_empty() {
+            open.push_back(pending);
+        }
+    }
+    let mut request = request.unwrap_or_else(|| RecoveryRequest {
+        new_admin: new_admin.clone(),
+        approvals: Vec::new(e),
+        initiated_at: now,
+        executable_at: None,
+        expires_at: now.saturating_add(RECOVERY_LIFETIME),
+    });
+    if !request.approvals.contains(guardian) {
+        request.approvals.push_back(guardian.clone());
+    }
+    if request.executable_at.is_none() && request.approvals.len() >= config.threshold {
+        let executable_at = now.saturating_add(config.delay);
+        request.executable_at = Some(executable_at);
+        request.expires_at = executable_at.saturating_add(RECOVERY_LIFETIME);
+        open = Vec::new(e);
+    }
+    open.push_back(request.clone());
+    storage::write_recoveries(e, owner, &open);
+    request
+}
+
+/// Install the admin of the request that met the threshold, once its delay has passed.
+pub fn execute_recovery(e: &Env, owner: &Address) -> Address {
+    let requests = storage::read_recoveries(e, owner);
+    if requests.is_empty() {
+        panic_with_error!(e, ContractError::NoRecoveryPending)
+    }
+    let request = match requests.iter().find(|request| request.executable_at.is_some()) {
+        Some(request) => request,
+        None => panic_with_error!(e, ContractError::RecoveryNotReady),
+    };
+    let now = e.ledger().timestamp();
+    if now > request.expires_at {
+        panic_with_error!(e, ContractError::RecoveryExpired)
+    }
+    match request.executable_at {
+        Some(at) if now >= at => {}
+        _ => panic_with_error!(e, ContractError::RecoveryNotReady),
+    }
+    storage::write_vault_admin(e, owner, &request.new_admin);
+    storage::write_recoveries(e, owner, &Vec::new(e));
+    // A handover proposed with the lost key must not outlive the recovery.
+    storage::write_pending_vault_admin(e, owner, None);
+    request.new_admin
+}
+
+/// Drop all open recovery requests. Caller must be the current vault admin.
+pub fn cancel_recovery(e: &Env, owner: &Address) {
+    if storage::read_recoveries(e, owner).is_empty() {
+        panic_with_error!(e, ContractError::NoRecoveryPending)
+    }
+    storage::write_recoveries(e, owner, &Vec::new(e));
+}
@@ -10,8 +10,13 @@ soroban keys generate vc_vault_admin --network testnet || true
 # Build + optimize
 sh scripts/build.sh
 
+# Deploy with a known salt: `initialize` must be called by vc_vault_admin with the same salt.
+SALT=$(openssl rand -hex 32)
+echo "Deploy salt: $SALT"
+
 echo "VC Vault contract ID:"
 soroban contract deploy \
   --wasm target/wasm32v1-none/release/vc_vault_contract.optimized.wasm \
   --source vc_vault_admin \
-  --network testnet
+  --network testnet \
+  --salt "$SALT"